@@ -84,6 +84,24 @@ impl<'a, I, T> Iterator for Mean<'a, I, T>
 }
 
 
+/// The value that `beats` every other, skipping values that aren't equal to themselves, such
+/// as NaN. A window holding only such values gives its first one, and an empty window `None`.
+fn extreme<'a, I, T, F>(values: I, beats: F) -> Option<T>
+    where I: Iterator<Item = &'a T>,
+          T: PartialOrd + Clone + 'a,
+          F: Fn(&T, &T) -> bool
+{
+    let mut values = values.peekable();
+    let first = *values.peek()?;
+    values.filter(|x| x.eq(x))
+        .fold(None, |best: Option<&T>, x| match best {
+            Some(m) if !beats(x, m) => Some(m),
+            _ => Some(x),
+        })
+        .or(Some(first))
+        .cloned()
+}
+
 #[derive(Clone)]
 pub struct Max<'a, I: 'a, T: 'a>
     where I: Iterator<Item = Window<'a, T>>,
@@ -110,13 +128,13 @@ impl<'a, I, T> Max<'a, I, T>
 
 impl<'a, I, T> Iterator for Max<'a, I, T>
     where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord + 'a
+          T: UtahNum + PartialOrd + 'a
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         match self.data.next() {
             None => return None,
-            Some((_, dat)) => return dat.iter().max().map(|x| x.clone()),
+            Some((_, dat)) => return extreme(dat.iter(), |x, m| x > m),
         }
 
 
@@ -151,13 +169,13 @@ impl<'a, I, T> Min<'a, I, T>
 
 impl<'a, I, T> Iterator for Min<'a, I, T>
     where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+          T: UtahNum + PartialOrd
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         match self.data.next() {
             None => return None,
-            Some((_, dat)) => return dat.iter().min().map(|x| x.clone()),
+            Some((_, dat)) => return extreme(dat.iter(), |x, m| x < m),
         }
 
 
@@ -244,7 +262,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Mean<'a, I, T>
 
 impl<'a, I, T> ToDataFrame<'a, T, T> for Max<'a, I, T>
     where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+          T: UtahNum + PartialOrd
 {
     fn as_df(self) -> Result<DataFrame<T>> {
         let other = self.other.clone();
@@ -295,7 +313,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Max<'a, I, T>
 
 impl<'a, I, T> ToDataFrame<'a, T, T> for Min<'a, I, T>
     where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+          T: UtahNum + PartialOrd
 {
     fn as_df(self) -> Result<DataFrame<T>> {
         let other = self.other.clone();
@@ -391,3 +409,88 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Sum<'a, I, T>
         Ok(Array::from(c))
     }
 }
+
+/// The distinct non-empty values, in order of first appearance, with the number of times
+/// each appears.
+pub(crate) fn distinct<'a, I, T>(values: I) -> Vec<(T, usize)>
+    where I: Iterator<Item = &'a T>,
+          T: UtahNum + 'a
+{
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for x in values.filter(|x| !x.is_empty()) {
+        match counts.iter_mut().find(|c| c.0 == *x) {
+            Some(c) => c.1 += 1,
+            None => counts.push((x.clone(), 1)),
+        }
+    }
+    counts
+}
+
+#[derive(Clone, Debug)]
+pub struct NUnique<'a, I: 'a, T: 'a>
+    where I: Iterator<Item = Window<'a, T>> + 'a,
+          T: UtahNum
+{
+    data: I,
+    other: Vec<String>,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T> NUnique<'a, I, T>
+    where I: Iterator<Item = Window<'a, T>>,
+          T: UtahNum
+{
+    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> NUnique<'a, I, T> {
+
+        NUnique {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T> Iterator for NUnique<'a, I, T>
+    where I: Iterator<Item = Window<'a, T>>,
+          T: UtahNum
+{
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data.next().map(|(_, dat)| distinct(dat.iter()).len())
+    }
+}
+
+impl<'a, I, T> ToDataFrame<'a, usize, f64> for NUnique<'a, I, T>
+    where I: Iterator<Item = Window<'a, T>>,
+          T: UtahNum
+{
+    fn as_df(self) -> Result<DataFrame<f64>> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        let def = ["0"];
+        let d = self.as_matrix()?;
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&def[..])?.index(&other[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&def[..])?;
+                Ok(df)
+            }
+        }
+    }
+    fn as_matrix(self) -> Result<Matrix<f64>> {
+        let res_dim = match self.axis {
+            UtahAxis::Row => (self.other.len(), 1),
+            UtahAxis::Column => (1, self.other.len()),
+        };
+        let c: Vec<f64> = self.map(|n| n as f64).collect();
+        Ok(Array::from_shape_vec(res_dim, c).unwrap())
+    }
+
+    fn as_array(self) -> Result<Row<f64>> {
+        let c: Vec<f64> = self.map(|n| n as f64).collect();
+        Ok(Array::from(c))
+    }
+}
@@ -47,15 +47,28 @@ impl<'a, I, T> Iterator for Concat<'a, I, T>
     }
 }
 
+/// Collect windows by label, keeping every window of a repeated label in order, so that a
+/// join pairs a row with each of its matches rather than only the last one.
+fn by_label<'a, I, T>(windows: I) -> HashMap<String, Vec<ArrayView1<'a, T>>>
+    where I: Iterator<Item = Window<'a, T>>
+{
+    let mut map: HashMap<String, Vec<ArrayView1<'a, T>>> = HashMap::new();
+    for (k, v) in windows {
+        map.entry(k).or_default().push(v);
+    }
+    map
+}
+
 #[derive(Clone)]
 pub struct InnerJoin<'a, L, T>
     where L: Iterator<Item = Window<'a, T>> + Clone,
           T: UtahNum + 'a
 {
     pub left: L,
-    pub right: HashMap<String, ArrayView1<'a, T>>,
+    pub right: HashMap<String, Vec<ArrayView1<'a, T>>>,
     pub left_columns: Vec<String>,
     pub right_columns: Vec<String>,
+    current: Option<(String, ArrayView1<'a, T>, usize)>,
 }
 
 impl<'a, L, T> InnerJoin<'a, L, T>
@@ -71,9 +84,10 @@ impl<'a, L, T> InnerJoin<'a, L, T>
     {
         InnerJoin {
             left: left,
-            right: right.collect(),
+            right: by_label(right),
             left_columns: left_columns,
             right_columns: right_columns,
+            current: None,
         }
     }
 }
@@ -88,20 +102,14 @@ impl<'a, L, T> Iterator for InnerJoin<'a, L, T>
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            match self.left.next() {
-                Some((k, lv)) => {
-                    let rv = self.right.get(&k);
-
-                    match rv {
-                        Some(v) => {
-                            return Some((k, lv, *v));
-                        }
-                        None => continue,
-                    }
+            if let Some((k, lv, pos)) = self.current.take() {
+                if let Some(&rv) = self.right.get(&k).and_then(|m| m.get(pos)) {
+                    self.current = Some((k.clone(), lv, pos + 1));
+                    return Some((k, lv, rv));
                 }
-                None => return None,
             }
-
+            let (k, lv) = self.left.next()?;
+            self.current = Some((k, lv, 0));
         }
     }
 }
@@ -112,9 +120,10 @@ pub struct OuterJoin<'a, L, T>
           T: UtahNum + 'a
 {
     left: L,
-    right: HashMap<String, ArrayView1<'a, T>>,
+    right: HashMap<String, Vec<ArrayView1<'a, T>>>,
     left_columns: Vec<String>,
     right_columns: Vec<String>,
+    current: Option<(String, ArrayView1<'a, T>, usize)>,
 }
 
 
@@ -131,9 +140,10 @@ impl<'a, L, T> OuterJoin<'a, L, T>
     {
         OuterJoin {
             left: left,
-            right: right.collect(),
+            right: by_label(right),
             left_columns: left_columns,
             right_columns: right_columns,
+            current: None,
         }
     }
 }
@@ -146,19 +156,19 @@ impl<'a, L, T> Iterator for OuterJoin<'a, L, T>
     type Item = (String, ArrayView1<'a, T>, Option<ArrayView1<'a, T>>);
 
     fn next(&mut self) -> Option<Self::Item> {
-
-        match self.left.next() {
-            Some((k, lv)) => {
-                let rv = self.right.get(&k);
-                match rv {
-                    Some(v) => return Some((k, lv, Some(*v))),
-                    None => Some((k, lv, None)),
+        loop {
+            if let Some((k, lv, pos)) = self.current.take() {
+                if let Some(&rv) = self.right.get(&k).and_then(|m| m.get(pos)) {
+                    self.current = Some((k.clone(), lv, pos + 1));
+                    return Some((k, lv, Some(rv)));
                 }
-
             }
-            None => None,
+            let (k, lv) = self.left.next()?;
+            if !self.right.contains_key(&k) {
+                return Some((k, lv, None));
+            }
+            self.current = Some((k, lv, 0));
         }
-
     }
 }
 
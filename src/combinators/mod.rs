@@ -4,10 +4,14 @@ pub use super::*;
 pub use self::aggregate::*;
 pub use self::interact::*;
 pub use self::process::*;
+pub use self::resample::*;
+pub use self::split::*;
 pub use self::transform::*;
 
 
 pub mod aggregate;
 pub mod interact;
 pub mod process;
+pub mod resample;
+pub mod split;
 pub mod transform;
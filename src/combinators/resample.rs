@@ -0,0 +1,307 @@
+//! Utah time-domain combinators.
+
+use super::*;
+use ndarray::Array;
+use chrono::{NaiveDateTime, TimeDelta};
+use std::convert::TryFrom;
+use std::mem;
+
+/// The rows of a time-indexed dataframe, bucketed into fixed intervals.
+///
+/// Buckets are aligned to multiples of the interval since the Unix epoch, and each is labelled
+/// by its start time. Buckets that no row falls into are kept, so the result is a regular grid
+/// from the earliest row to the latest.
+#[derive(Clone, Debug)]
+pub struct Resample<'a, T: 'a>
+    where T: UtahNum
+{
+    pub df: &'a DataFrame<T>,
+    /// Each row's timestamp and position, in time order.
+    pub times: Vec<(NaiveDateTime, usize)>,
+    pub origin: NaiveDateTime,
+    pub freq: TimeDelta,
+    pub buckets: usize,
+    /// `freq` in nanoseconds.
+    step: i128,
+}
+
+/// The length of `d` in nanoseconds, which always fits in an `i128`.
+fn nanos(d: TimeDelta) -> i128 {
+    d.num_seconds() as i128 * 1_000_000_000 + d.subsec_nanos() as i128
+}
+
+/// The duration of `n` nanoseconds, if it is within the range of `TimeDelta`.
+fn from_nanos(n: i128) -> Option<TimeDelta> {
+    let secs = i64::try_from(n.div_euclid(1_000_000_000)).ok()?;
+    TimeDelta::new(secs, n.rem_euclid(1_000_000_000) as u32)
+}
+
+impl<'a, T> Resample<'a, T>
+    where T: UtahNum
+{
+    /// Bucket the rows of `df`, whose timestamps are `times`, into intervals of `freq`.
+    ///
+    /// Fails with `InvalidFrequency` if `freq` isn't positive, or if the buckets needed to
+    /// cover the timestamps can't be counted or labelled.
+    pub(crate) fn new(df: &'a DataFrame<T>,
+                      times: Vec<NaiveDateTime>,
+                      freq: TimeDelta)
+                      -> Result<Resample<'a, T>> {
+        let invalid = |why: &str| -> Error {
+            ErrorKind::InvalidFrequency(format!("{} ({})", format_duration(freq), why)).into()
+        };
+        let step = nanos(freq);
+        if step <= 0 {
+            return Err(invalid("not positive"));
+        }
+        let mut times: Vec<(NaiveDateTime, usize)> = times.into_iter().zip(0..).collect();
+        times.sort_by_key(|&(t, _)| t);
+        let epoch = NaiveDateTime::default();
+        let origin = match times.first() {
+            Some(&(t, _)) => {
+                let n = nanos(t.signed_duration_since(epoch));
+                from_nanos(n.div_euclid(step) * step)
+                    .and_then(|d| epoch.checked_add_signed(d))
+                    .ok_or_else(|| invalid("the first bucket starts out of range"))?
+            }
+            None => epoch,
+        };
+        let buckets = match times.last() {
+            Some(&(t, _)) => {
+                usize::try_from(nanos(t.signed_duration_since(origin)) / step)
+                    .ok()
+                    .and_then(|b| b.checked_add(1))
+                    .filter(|&b| {
+                        // The resampled data must be addressable.
+                        let size = b.checked_mul(df.columns.len().max(1))
+                            .and_then(|n| n.checked_mul(mem::size_of::<T>()));
+                        matches!(size, Some(n) if n <= isize::MAX as usize)
+                    })
+                    .ok_or_else(|| invalid("too many buckets"))?
+            }
+            None => 0,
+        };
+        Ok(Resample {
+            df,
+            times,
+            origin,
+            freq,
+            buckets,
+            step,
+        })
+    }
+
+    fn bucket(&self, t: NaiveDateTime) -> usize {
+        (self.offset(t) / self.step) as usize
+    }
+
+    /// Nanoseconds from the first bucket's start to `t`.
+    fn offset(&self, t: NaiveDateTime) -> i128 {
+        nanos(t.signed_duration_since(self.origin))
+    }
+
+    /// The start of bucket `b`, as nanoseconds from the first bucket's start.
+    fn start(&self, b: usize) -> i128 {
+        self.step * b as i128
+    }
+
+    fn to_df(&self, values: Vec<T>) -> DataFrame<T> {
+        let index = (0..self.buckets)
+            .map(|b| {
+                // Bucket starts lie between the first and the last timestamp.
+                (self.origin + from_nanos(self.start(b)).unwrap())
+                    .format(DATETIME_FORMATS[0])
+                    .to_string()
+            })
+            .collect();
+        DataFrame {
+            columns: self.df.columns.clone(),
+            data: Array::from_shape_vec((self.buckets, self.df.columns.len()), values).unwrap(),
+            index,
+        }
+    }
+
+    /// The positions of the rows in each bucket, in time order.
+    fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![Vec::new(); self.buckets];
+        for &(t, i) in self.times.iter() {
+            groups[self.bucket(t)].push(i);
+        }
+        groups
+    }
+
+    /// Run `f` over the columns of each bucket's rows. Buckets with no rows give empty cells.
+    fn aggregate<F>(&self, f: F) -> Result<DataFrame<T>>
+        where F: for<'b> Fn(DataFrameIterator<'b, T>) -> Result<Row<T>>
+    {
+        let ncols = self.df.columns.len();
+        let mut values = Vec::with_capacity(self.buckets * ncols);
+        for rows in self.groups() {
+            if rows.is_empty() {
+                values.extend((0..ncols).map(|_| T::empty()));
+            } else {
+                let bucket = self.df.take_rows(&rows[..]);
+                values.extend(f(bucket.df_iter(UtahAxis::Column))?.iter().cloned());
+            }
+        }
+        Ok(self.to_df(values))
+    }
+
+    /// Sum each column within each bucket.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0], [2.0], [4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a)
+    ///     .index(&["2020-01-01 10:10:00", "2020-01-01 10:50:00", "2020-01-01 12:00:00"])
+    ///     .unwrap();
+    /// let hourly = df.resample("1h").unwrap().sumdf().unwrap();
+    /// assert_eq!(hourly.index, vec!["2020-01-01 10:00:00", "2020-01-01 11:00:00",
+    ///                               "2020-01-01 12:00:00"]);
+    /// assert_eq!(hourly.data[[0, 0]], 3.0);
+    /// assert!(hourly.data[[1, 0]].is_nan());
+    /// ```
+    pub fn sumdf(&self) -> Result<DataFrame<T>> {
+        self.aggregate(|columns| columns.sumdf().as_array())
+    }
+
+    /// Average each column within each bucket.
+    pub fn mean(&self) -> Result<DataFrame<T>> {
+        self.aggregate(|columns| columns.mean().as_array())
+    }
+
+    /// Take the maximum of each column within each bucket.
+    pub fn maxdf(&self) -> Result<DataFrame<T>>
+        where T: PartialOrd
+    {
+        self.aggregate(|columns| columns.maxdf().as_array())
+    }
+
+    /// Take the minimum of each column within each bucket.
+    pub fn mindf(&self) -> Result<DataFrame<T>>
+        where T: PartialOrd
+    {
+        self.aggregate(|columns| columns.mindf().as_array())
+    }
+
+    /// Take the earliest row of each bucket.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[2.0], [1.0]]))
+    ///     .index(&["2020-01-01 10:50:00", "2020-01-01 10:10:00"])
+    ///     .unwrap();
+    /// let hourly = df.resample("1h").unwrap();
+    /// assert_eq!(hourly.first().unwrap().data, arr2(&[[1.0]]));
+    /// assert_eq!(hourly.last().unwrap().data, arr2(&[[2.0]]));
+    /// ```
+    pub fn first(&self) -> Result<DataFrame<T>> {
+        self.aggregate(|columns| Ok(columns.map(|(_, x)| x[0].clone()).collect()))
+    }
+
+    /// Take the latest row of each bucket.
+    pub fn last(&self) -> Result<DataFrame<T>> {
+        self.aggregate(|columns| Ok(columns.map(|(_, x)| x[x.len() - 1].clone()).collect()))
+    }
+
+    /// Take, for each bucket start and column, the latest non-empty value at or before it.
+    /// Used with an interval finer than the data, this upsamples by forward-filling.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [3.0]]))
+    ///     .index(&["2020-01-01 10:00:00", "2020-01-01 11:00:00"])
+    ///     .unwrap();
+    /// let filled = df.resample("30min").unwrap().ffill();
+    /// assert_eq!(filled.data, arr2(&[[1.0], [1.0], [3.0]]));
+    /// ```
+    pub fn ffill(&self) -> DataFrame<T> {
+        let ncols = self.df.columns.len();
+        let mut last: Vec<T> = vec![T::empty(); ncols];
+        let mut next = 0;
+        let mut values = Vec::with_capacity(self.buckets * ncols);
+        for b in 0..self.buckets {
+            while next < self.times.len() && self.offset(self.times[next].0) <= self.start(b) {
+                for (j, x) in self.df.data.row(self.times[next].1).iter().enumerate() {
+                    if !x.is_empty() {
+                        last[j] = x.clone();
+                    }
+                }
+                next += 1;
+            }
+            values.extend(last.iter().cloned());
+        }
+        self.to_df(values)
+    }
+
+    /// Interpolate each column linearly in time between the non-empty values on either side
+    /// of each bucket start. Bucket starts before the first or after the last value of a
+    /// column give empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [3.0]]))
+    ///     .index(&["2020-01-01 10:00:00", "2020-01-01 11:00:00"])
+    ///     .unwrap();
+    /// let filled = df.resample("15min").unwrap().interpolate();
+    /// assert_eq!(filled.data, arr2(&[[1.0], [1.5], [2.0], [2.5], [3.0]]));
+    /// ```
+    pub fn interpolate(&self) -> DataFrame<T>
+        where T: CastFrom<f64>,
+              f64: CastFrom<T>
+    {
+        let ncols = self.df.columns.len();
+        let columns: Vec<Vec<(i128, f64)>> = (0..ncols)
+            .map(|j| {
+                self.times
+                    .iter()
+                    .map(|&(t, i)| (self.offset(t), &self.df.data[[i, j]]))
+                    .filter(|&(_, x)| !x.is_empty())
+                    .map(|(t, x)| (t, f64::cast_from(x)))
+                    .collect()
+            })
+            .collect();
+        let mut values = Vec::with_capacity(self.buckets * ncols);
+        for b in 0..self.buckets {
+            let at = self.start(b);
+            for points in columns.iter() {
+                let after = points.partition_point(|&(t, _)| t <= at);
+                let value = match (after.checked_sub(1).map(|p| points[p]), points.get(after)) {
+                    (Some((t, x)), _) if t == at => T::cast_from(&x),
+                    (Some((t0, x0)), Some(&(t1, x1))) => {
+                        let w = (at - t0) as f64 / (t1 - t0) as f64;
+                        T::cast_from(&(x0 + (x1 - x0) * w))
+                    }
+                    _ => T::empty(),
+                };
+                values.push(value);
+            }
+        }
+        self.to_df(values)
+    }
+
+    /// Take the row at each bucket start, leaving buckets with no row at their exact start
+    /// empty.
+    pub fn asfreq(&self) -> DataFrame<T> {
+        let ncols = self.df.columns.len();
+        let mut rows: Vec<Option<usize>> = vec![None; self.buckets];
+        for &(t, i) in self.times.iter() {
+            let b = self.bucket(t);
+            if self.offset(t) == self.start(b) {
+                rows[b] = Some(i);
+            }
+        }
+        let mut values = Vec::with_capacity(self.buckets * ncols);
+        for row in rows {
+            match row {
+                Some(i) => values.extend(self.df.data.row(i).iter().cloned()),
+                None => values.extend((0..ncols).map(|_| T::empty())),
+            }
+        }
+        self.to_df(values)
+    }
+}
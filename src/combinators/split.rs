@@ -0,0 +1,47 @@
+//! Utah splitting combinators.
+
+use super::*;
+use std::iter::Iterator;
+
+/// An iterator over the `(train, test)` pairs of a k-fold split.
+#[derive(Clone, Debug)]
+pub struct KFold<'a, T: 'a>
+    where T: UtahNum
+{
+    pub df: &'a DataFrame<T>,
+    pub folds: Vec<Vec<usize>>,
+    pub current: usize,
+}
+
+impl<'a, T> KFold<'a, T>
+    where T: UtahNum
+{
+    pub fn new(df: &'a DataFrame<T>, folds: Vec<Vec<usize>>) -> KFold<'a, T> {
+        KFold {
+            df,
+            folds,
+            current: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for KFold<'a, T>
+    where T: UtahNum
+{
+    type Item = (DataFrame<T>, DataFrame<T>);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.folds.len() {
+            return None;
+        }
+        let test = &self.folds[self.current];
+        let mut train: Vec<usize> = self.folds
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != self.current)
+            .flat_map(|(_, f)| f.iter().cloned())
+            .collect();
+        train.sort_unstable();
+        self.current += 1;
+        Some((self.df.take_rows(&train[..]), self.df.take_rows(&test[..])))
+    }
+}
@@ -4,10 +4,13 @@ use super::*;
 use std::iter::Iterator;
 use ndarray::iter::{AxisIter, AxisIterMut};
 use std::slice::Iter;
-use ndarray::{ArrayView1, ArrayViewMut1, Dim, Ix};
+use ndarray::{Array, ArrayView1, ArrayViewMut1, Axis, Dim, Ix};
+#[cfg(feature = "serde-1")]
+use serde_derive::{Deserialize, Serialize};
 
 /// A read-only dataframe.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
 pub struct DataFrame<T>
     where T: UtahNum
 {
@@ -27,6 +30,17 @@ pub struct DataFrameMut<'a, T: 'a>
 }
 
 
+/// A read-only, zero-copy view into a dataframe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataFrameView<'a, T: 'a>
+    where T: UtahNum
+{
+    pub columns: Vec<String>,
+    pub data: MatrixView<'a, T>,
+    pub index: Vec<String>,
+}
+
+
 /// The read-only dataframe iterator
 #[derive(Clone)]
 pub struct DataFrameIterator<'a, T: 'a>
@@ -100,3 +114,117 @@ impl<'a, T> DataFrameMut<'a, T>
 
     }
 }
+
+
+impl<T> DataFrame<T>
+    where T: UtahNum
+{
+    /// Copy the rows at the given positions into a new dataframe, keeping their labels.
+    pub(crate) fn take_rows(&self, rows: &[usize]) -> DataFrame<T> {
+        let ncols = self.columns.len();
+        let mut c = Vec::with_capacity(rows.len() * ncols);
+        for &i in rows {
+            c.extend(self.data.row(i).iter().cloned());
+        }
+        DataFrame {
+            columns: self.columns.clone(),
+            data: Array::from_shape_vec((rows.len(), ncols), c).unwrap(),
+            index: rows.iter().map(|&i| self.index[i].clone()).collect(),
+        }
+    }
+
+    /// The position of a column, or `InvalidColumnName` if there is no such column.
+    pub(crate) fn column_position(&self, column: &str) -> Result<usize> {
+        match self.columns.iter().position(|c| c == column) {
+            Some(j) => Ok(j),
+            None => Err(ErrorKind::InvalidColumnName(column.to_string()).into()),
+        }
+    }
+}
+
+
+impl<'a, T> DataFrameView<'a, T>
+    where T: 'a + UtahNum
+{
+    /// Get the dimensions of the view.
+    pub fn shape(&self) -> (usize, usize) {
+        self.data.dim()
+    }
+
+    /// Return a dataframe iterator over the specified `UtahAxis` of the view.
+    pub fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T> {
+        match axis {
+            UtahAxis::Row => {
+                DataFrameIterator {
+                    names: self.index.iter(),
+                    data: self.data.axis_iter(Axis(0)),
+                    other: self.columns.clone(),
+                    axis: UtahAxis::Row,
+                }
+            }
+            UtahAxis::Column => {
+                DataFrameIterator {
+                    names: self.columns.iter(),
+                    data: self.data.axis_iter(Axis(1)),
+                    other: self.index.clone(),
+                    axis: UtahAxis::Column,
+                }
+            }
+        }
+    }
+
+    /// Copy the view into an owned dataframe.
+    pub fn to_df(self) -> Result<DataFrame<T>> {
+        let d = self.data.to_owned();
+        let df = DataFrame::new(d).columns(&self.columns[..])?.index(&self.index[..])?;
+        Ok(df)
+    }
+}
+
+#[cfg(all(test, feature = "serde-1"))]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn round_trip_through_bincode() {
+        let df: DataFrame<f64> = DataFrame::new(arr2(&[[1.5, ::std::f64::NAN], [-2.0, 4.0]]))
+            .columns(&["a", "b"])
+            .unwrap()
+            .index(&["x", "y"])
+            .unwrap();
+        let bytes = bincode::serialize(&df).unwrap();
+        let back: DataFrame<f64> = bincode::deserialize(&bytes[..]).unwrap();
+        assert_eq!(back.columns, df.columns);
+        assert_eq!(back.index, df.index);
+        assert_eq!(back.data[[0, 0]], 1.5);
+        assert!(back.data[[0, 1]].is_nan());
+        assert_eq!(back.data[[1, 1]], 4.0);
+
+        let cells = arr2(&[[InnerType::Float(0.5), InnerType::Int32(-3), InnerType::Int64(7)],
+                           [InnerType::Bool(true), InnerType::from("text"), InnerType::Empty],
+                           [InnerType::parse("2020-03-01", DType::Date).unwrap(),
+                            InnerType::parse("2020-03-01 14:30:00", DType::DateTime).unwrap(),
+                            InnerType::Duration(TimeDelta::minutes(90))]]);
+        let mixed: DataFrame<InnerType> = DataFrame::new(cells).columns(&["p", "q", "r"]).unwrap();
+        let bytes = bincode::serialize(&mixed).unwrap();
+        let back: DataFrame<InnerType> = bincode::deserialize(&bytes[..]).unwrap();
+        assert_eq!(back, mixed);
+
+        let schema = Schema::new(vec![Field::new("id", DType::Int64, false),
+                                      Field::new("when", DType::DateTime, true)]);
+        let bytes = bincode::serialize(&schema).unwrap();
+        let back: Schema = bincode::deserialize(&bytes[..]).unwrap();
+        assert_eq!(back, schema);
+
+        let colour = Categorical::new(vec![Some("red"), None, Some("blue"), Some("red")]);
+        let bytes = bincode::serialize(&colour).unwrap();
+        let back: Categorical = bincode::deserialize(&bytes[..]).unwrap();
+        assert_eq!(back, colour);
+
+        let bytes = bincode::serialize(&(UtahAxis::Column, ImputeStrategy::Mean)).unwrap();
+        let back: (UtahAxis, ImputeStrategy) = bincode::deserialize(&bytes[..]).unwrap();
+        assert!(matches!(back, (UtahAxis::Column, ImputeStrategy::Mean)));
+    }
+}
@@ -0,0 +1,111 @@
+//! Element-wise arithmetic between dataframes, and with scalars.
+//!
+//! Dataframes are aligned on both their index and their columns before combining, so
+//! frames with different or differently ordered labels can be added directly:
+//!
+//! ```
+//! use utah2::prelude::*;
+//! use ndarray::arr2;
+//! let a : DataFrame<f64> = DataFrame::new(arr2(&[[1.0, 2.0], [3.0, 4.0]]))
+//!     .columns(&["x", "y"]).unwrap()
+//!     .index(&["r1", "r2"]).unwrap();
+//! let b : DataFrame<f64> = DataFrame::new(arr2(&[[10.0, 20.0]]))
+//!     .columns(&["y", "z"]).unwrap()
+//!     .index(&["r2"]).unwrap();
+//! let sum = &a + &b;
+//! assert_eq!(sum.columns, vec!["x", "y", "z"]);
+//! assert_eq!(sum.index, vec!["r1", "r2"]);
+//! assert_eq!(sum.data[[1, 1]], 14.0);
+//! assert!(sum.data[[0, 1]].is_nan());
+//! assert_eq!((&a * 2.0).data, arr2(&[[2.0, 4.0], [6.0, 8.0]]));
+//! ```
+use super::*;
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Div, Mul, Sub};
+use ndarray::Array;
+
+/// The union of two label lists, keeping the order of `left` and appending the labels only
+/// found in `right`, with the position of each label on either side. A label repeated on the
+/// right matches its first occurrence there.
+fn union(left: &[String],
+         right: &[String])
+         -> (Vec<String>, Vec<Option<usize>>, Vec<Option<usize>>) {
+    let mut first: HashMap<&str, usize> = HashMap::new();
+    for (i, x) in right.iter().enumerate() {
+        first.entry(x.as_str()).or_insert(i);
+    }
+    let known: HashSet<&str> = left.iter().map(|x| x.as_str()).collect();
+    let mut labels = left.to_vec();
+    let mut lpos: Vec<Option<usize>> = (0..left.len()).map(Some).collect();
+    let mut rpos: Vec<Option<usize>> = left.iter()
+        .map(|x| first.get(x.as_str()).cloned())
+        .collect();
+    for (i, x) in right.iter().enumerate() {
+        if first[x.as_str()] == i && !known.contains(x.as_str()) {
+            labels.push(x.clone());
+            lpos.push(None);
+            rpos.push(Some(i));
+        }
+    }
+    (labels, lpos, rpos)
+}
+
+/// Combine the cells of two dataframes with the same index label and column name, over the
+/// union of their labels. Cells present on only one side are empty.
+fn align<T, F>(left: &DataFrame<T>, right: &DataFrame<T>, f: F) -> DataFrame<T>
+    where T: UtahNum,
+          F: Fn(T, T) -> T
+{
+    let (index, li, ri) = union(&left.index[..], &right.index[..]);
+    let (columns, lj, rj) = union(&left.columns[..], &right.columns[..]);
+    let mut c = Vec::with_capacity(index.len() * columns.len());
+    for (a, b) in li.iter().zip(ri.iter()) {
+        for (x, y) in lj.iter().zip(rj.iter()) {
+            c.push(match (*a, *b, *x, *y) {
+                (Some(a), Some(b), Some(x), Some(y)) => {
+                    f(left.data[[a, x]].clone(), right.data[[b, y]].clone())
+                }
+                _ => T::empty(),
+            });
+        }
+    }
+    DataFrame {
+        data: Array::from_shape_vec((index.len(), columns.len()), c).unwrap(),
+        columns,
+        index,
+    }
+}
+
+macro_rules! impl_arith {
+    ($($trait:ident, $method:ident);*) => {
+        $(
+        /// Combine the cells with matching index label and column name. The result covers
+        /// the union of both dataframes' labels, with empty cells where only one side has a
+        /// value.
+        impl<'a, 'b, T> $trait<&'b DataFrame<T>> for &'a DataFrame<T>
+            where T: UtahNum
+        {
+            type Output = DataFrame<T>;
+            fn $method(self, other: &'b DataFrame<T>) -> DataFrame<T> {
+                align(self, other, |x, y| x.$method(y))
+            }
+        }
+
+        /// Combine every cell with a scalar.
+        impl<'a, T> $trait<T> for &'a DataFrame<T>
+            where T: UtahNum
+        {
+            type Output = DataFrame<T>;
+            fn $method(self, other: T) -> DataFrame<T> {
+                DataFrame {
+                    columns: self.columns.clone(),
+                    data: self.data.mapv(|x| x.$method(other.clone())),
+                    index: self.index.clone(),
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_arith!(Add, add; Sub, sub; Mul, mul; Div, div);
@@ -0,0 +1,144 @@
+use super::*;
+use ndarray::Array;
+
+impl<T> Cast<T> for DataFrame<T>
+    where T: UtahNum
+{
+    /// Convert every element to `U`, truncating floats and saturating integers that don't
+    /// fit. Values that can't be converted become empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.7, -1.0], [3e10, 4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a);
+    /// let ids : DataFrame<i32> = df.cast();
+    /// assert_eq!(ids.data, arr2(&[[2, -1], [i32::MAX, 4]]));
+    /// ```
+    fn cast<U>(&self) -> DataFrame<U>
+        where U: UtahNum + CastFrom<T>
+    {
+        DataFrame {
+            columns: self.columns.clone(),
+            data: self.data.map(U::cast_from),
+            index: self.index.clone(),
+        }
+    }
+
+    /// Convert every element to `U`, failing with a `CastError` naming the first cell that
+    /// can't be represented exactly.
+    ///
+    /// Empty cells are never an error: `NaN` and `InnerType::Empty` become the empty value of
+    /// `U` (`NaN`, `0` or `InnerType::Empty`), whatever the target type. Integer zeros are
+    /// ordinary values and stay zero.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.0], [3.0, 4.5]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let err = df.try_cast::<i64>().unwrap_err();
+    /// assert_eq!(err.to_string(),
+    ///            "cannot cast value at row '1', column 'b': 4.5 does not fit in the target type");
+    ///
+    /// let gaps : DataFrame<f64> = DataFrame::new(arr2(&[[std::f64::NAN, 1.0]]));
+    /// assert_eq!(gaps.try_cast::<i32>().unwrap().data, arr2(&[[0, 1]]));
+    /// let mixed : DataFrame<InnerType> = DataFrame::new(arr2(&[[InnerType::Empty]]));
+    /// assert_eq!(mixed.try_cast::<i64>().unwrap().data, arr2(&[[0]]));
+    /// assert!(mixed.try_cast::<f64>().unwrap().data[[0, 0]].is_nan());
+    /// ```
+    fn try_cast<U>(&self) -> Result<DataFrame<U>>
+        where U: UtahNum + CastFrom<T>
+    {
+        let mut values = Vec::with_capacity(self.data.len());
+        for ((i, j), x) in self.data.indexed_iter() {
+            match U::try_cast_from(x) {
+                Some(v) => values.push(v),
+                None => {
+                    return Err(ErrorKind::CastError(self.index[i].clone(),
+                                                    self.columns[j].clone(),
+                                                    format!("{:?} does not fit in the target \
+                                                             type",
+                                                            x))
+                        .into())
+                }
+            }
+        }
+        Ok(DataFrame {
+            columns: self.columns.clone(),
+            data: Array::from_shape_vec(self.data.dim(), values).unwrap(),
+            index: self.index.clone(),
+        })
+    }
+}
+
+impl CastColumns for DataFrame<InnerType> {
+    /// Convert one column of a mixed-type dataframe to `dtype`, truncating floats and
+    /// saturating integers that don't fit. Values that can't be converted become empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("1.5")], [InnerType::from("n/a")]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["x"]).unwrap();
+    /// let df = df.cast_column("x", DType::Float).unwrap();
+    /// assert_eq!(df.data[[0, 0]], InnerType::Float(1.5));
+    /// assert_eq!(df.data[[1, 0]], InnerType::Empty);
+    /// ```
+    fn cast_column(&self, column: &str, dtype: DType) -> Result<DataFrame<InnerType>> {
+        let j = self.column_position(column)?;
+        let mut df = self.clone();
+        df.data.column_mut(j).mapv_inplace(|x| x.convert(dtype));
+        Ok(df)
+    }
+
+    /// Convert one column of a mixed-type dataframe to `dtype`, failing with a `CastError`
+    /// naming the first cell that can't be represented exactly.
+    fn try_cast_column(&self, column: &str, dtype: DType) -> Result<DataFrame<InnerType>> {
+        let j = self.column_position(column)?;
+        let mut df = self.clone();
+        for (i, x) in df.data.column_mut(j).iter_mut().enumerate() {
+            match x.try_convert(dtype) {
+                Some(v) => *x = v,
+                None => {
+                    return Err(ErrorKind::CastError(self.index[i].clone(),
+                                                    column.to_string(),
+                                                    format!("cannot convert {:?} to {:?}",
+                                                            x,
+                                                            dtype))
+                        .into())
+                }
+            }
+        }
+        Ok(df)
+    }
+
+    /// Convert every column to the type given by `schema`, then check that only nullable
+    /// columns hold empty cells. The dataframe's columns must match the schema's, in order.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("1"), InnerType::from(2.0)]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["id", "score"]).unwrap();
+    /// let schema = Schema::new(vec![Field::new("id", DType::Int64, false),
+    ///                               Field::new("score", DType::Float, true)]);
+    /// let df = df.cast_schema(&schema).unwrap();
+    /// assert_eq!(df.data[[0, 0]], InnerType::Int64(1));
+    /// assert_eq!(df.schema().fields[0].dtype, DType::Int64);
+    /// ```
+    fn cast_schema(&self, schema: &Schema) -> Result<DataFrame<InnerType>> {
+        if self.columns != schema.names() {
+            return Err(ErrorKind::SchemaMismatch(format!("expected columns {:?}, found {:?}",
+                                                         schema.names(),
+                                                         self.columns))
+                .into());
+        }
+        let mut df = self.clone();
+        for field in schema.fields.iter() {
+            df = df.try_cast_column(&field.name, field.dtype)?;
+        }
+        schema.validate(&df.schema())?;
+        Ok(df)
+    }
+}
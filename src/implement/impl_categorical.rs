@@ -0,0 +1,25 @@
+use super::*;
+
+impl ToCategorical for DataFrame<InnerType> {
+    /// Encode a column as a `Categorical`. Strings are kept as they are, other values are
+    /// written out as strings, and empty cells are missing.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("red")], [InnerType::from("blue")], [InnerType::from("red")]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["colour"]).unwrap();
+    /// let colour = df.to_categorical("colour").unwrap();
+    /// assert_eq!(colour.codes, vec![0, 1, 0]);
+    /// let back = df.with_column("colour", colour.to_column()).unwrap();
+    /// assert_eq!(back, df);
+    /// ```
+    fn to_categorical(&self, column: &str) -> Result<Categorical> {
+        let j = self.column_position(column)?;
+        Ok(Categorical::new(self.data.column(j).iter().map(|x| match *x {
+            InnerType::Str(ref s) => Some(s.clone()),
+            ref other if other.is_empty() => None,
+            ref other => Some(other.to_string()),
+        })))
+    }
+}
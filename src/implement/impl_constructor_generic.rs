@@ -2,6 +2,7 @@ use super::*;
 use std::string::ToString;
 use std::iter::Iterator;
 use ndarray::Axis;
+use std::collections::HashSet;
 
 impl<'a, T> Constructor<'a, T> for DataFrame<T>
     where T: UtahNum + 'a
@@ -139,6 +140,26 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
         Ok(self)
     }
 
+    /// Check that no two rows share an index label and no two columns share a name. `index`
+    /// and `columns` only check lengths, so call this after them when the labels come from
+    /// untrusted data:
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.0], [3.0, 4.0]]);
+    /// let df = DataFrame::<f64>::new(a).index(&["x", "x"]).unwrap().verify_integrity();
+    /// assert!(df.is_err());
+    /// ```
+    fn verify_integrity(self) -> Result<DataFrame<T>> {
+        for labels in [&self.index, &self.columns].iter() {
+            let mut seen = HashSet::new();
+            if let Some(label) = labels.iter().find(|x| !seen.insert(x.as_str())) {
+                return Err(ErrorKind::DuplicateLabel(label.clone()).into());
+            }
+        }
+        Ok(self)
+    }
 
     /// Return a dataframe iterator over the specified `UtahAxis`.
     ///
@@ -0,0 +1,140 @@
+use super::*;
+use ndarray::Array;
+use chrono::{Datelike, TimeDelta, Timelike};
+
+impl DateTimeColumns for DataFrame<InnerType> {
+    /// Parse the strings of a column as timestamps in the given `strftime` format. Dates
+    /// become midnight, timestamps and empty cells are kept, and any other value is a
+    /// `CastError`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("03/01/2020 14:30")], [InnerType::Empty]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["t"]).unwrap();
+    /// let df = df.parse_datetime_column("t", "%d/%m/%Y %H:%M").unwrap();
+    /// assert_eq!(df.data[[0, 0]].dtype(), Some(DType::DateTime));
+    /// assert_eq!(df.data[[1, 0]], InnerType::Empty);
+    /// ```
+    fn parse_datetime_column(&self, column: &str, format: &str) -> Result<DataFrame<InnerType>> {
+        let j = self.column_position(column)?;
+        let mut df = self.clone();
+        for (i, x) in df.data.column_mut(j).iter_mut().enumerate() {
+            let parsed = match *x {
+                InnerType::Str(ref s) => InnerType::parse_datetime(s, format),
+                ref other => other.try_convert(DType::DateTime),
+            };
+            match parsed {
+                Some(t) => *x = t,
+                None => {
+                    return Err(ErrorKind::CastError(self.index[i].clone(),
+                                                    column.to_string(),
+                                                    format!("cannot parse {} with format '{}'",
+                                                            x,
+                                                            format))
+                        .into())
+                }
+            }
+        }
+        Ok(df)
+    }
+
+    /// Extract a field from each timestamp or date in a column, as `Int32` cells. Empty cells
+    /// and values that aren't timestamps give empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let t = InnerType::parse("2020-03-01 14:30:00", DType::DateTime).unwrap();
+    /// let df : DataFrame<InnerType> = DataFrame::new(arr2(&[[t]])).columns(&["t"]).unwrap();
+    /// let hours = df.datetime_component("t", DateComponent::Hour).unwrap();
+    /// assert_eq!(hours[0], InnerType::Int32(14));
+    /// let df = df.with_column("month", df.datetime_component("t", DateComponent::Month).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(df.data[[0, 1]], InnerType::Int32(3));
+    /// ```
+    fn datetime_component(&self,
+                              column: &str,
+                              component: DateComponent)
+                              -> Result<Column<InnerType>> {
+        let j = self.column_position(column)?;
+        Ok(self.data.column(j).map(|x| {
+            let t = match *x {
+                InnerType::DateTime(t) => t,
+                InnerType::Date(d) => d.and_time(Default::default()),
+                _ => return InnerType::Empty,
+            };
+            InnerType::Int32(match component {
+                DateComponent::Year => t.year(),
+                DateComponent::Month => t.month() as i32,
+                DateComponent::Day => t.day() as i32,
+                DateComponent::Hour => t.hour() as i32,
+                DateComponent::Minute => t.minute() as i32,
+                DateComponent::Second => t.second() as i32,
+                DateComponent::Weekday => t.weekday().num_days_from_monday() as i32,
+                DateComponent::DayOfYear => t.ordinal() as i32,
+            })
+        }))
+    }
+
+    /// Add a duration to every timestamp or date in a column. Cells of other types become
+    /// empty.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// use chrono::TimeDelta;
+    /// let t = InnerType::parse("2020-03-01 23:30:00", DType::DateTime).unwrap();
+    /// let df : DataFrame<InnerType> = DataFrame::new(arr2(&[[t]])).columns(&["t"]).unwrap();
+    /// let df = df.add_duration("t", TimeDelta::hours(1)).unwrap();
+    /// assert_eq!(df.data[[0, 0]], InnerType::parse("2020-03-02 00:30:00", DType::DateTime).unwrap());
+    /// ```
+    fn add_duration(&self, column: &str, duration: TimeDelta) -> Result<DataFrame<InnerType>> {
+        let j = self.column_position(column)?;
+        let mut df = self.clone();
+        df.data.column_mut(j).mapv_inplace(|x| match x {
+            InnerType::DateTime(_) | InnerType::Date(_) => x + InnerType::Duration(duration),
+            _ => InnerType::Empty,
+        });
+        Ok(df)
+    }
+
+    /// Move a timestamp column into the index, removing it from the columns. Every cell of
+    /// the column must be a timestamp or a date.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("2020-03-01 10:00:00"), InnerType::from(1.0)]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["t", "x"]).unwrap();
+    /// let df = df.try_cast_column("t", DType::DateTime).unwrap().set_datetime_index("t").unwrap();
+    /// assert_eq!(df.columns, vec!["x"]);
+    /// assert_eq!(df.index, vec!["2020-03-01 10:00:00"]);
+    /// assert_eq!(df.datetime_index().unwrap()[0].to_string(), "2020-03-01 10:00:00");
+    /// ```
+    fn set_datetime_index(&self, column: &str) -> Result<DataFrame<InnerType>> {
+        let j = self.column_position(column)?;
+        let mut index = Vec::with_capacity(self.index.len());
+        for (i, x) in self.data.column(j).iter().enumerate() {
+            match x.try_convert(DType::DateTime) {
+                Some(InnerType::DateTime(t)) => index.push(t.format(DATETIME_FORMATS[0]).to_string()),
+                _ => {
+                    return Err(ErrorKind::CastError(self.index[i].clone(),
+                                                    column.to_string(),
+                                                    format!("{} is not a timestamp", x))
+                        .into())
+                }
+            }
+        }
+        let keep: Vec<usize> = (0..self.columns.len()).filter(|&c| c != j).collect();
+        let mut values = Vec::with_capacity(self.index.len() * keep.len());
+        for row in self.data.outer_iter() {
+            values.extend(keep.iter().map(|&c| row[c].clone()));
+        }
+        Ok(DataFrame {
+            columns: keep.iter().map(|&c| self.columns[c].clone()).collect(),
+            data: Array::from_shape_vec((self.index.len(), keep.len()), values).unwrap(),
+            index,
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use super::*;
+use std::fmt;
+
+impl<'a, T> Render<'a, T> for DataFrame<T>
+    where T: 'a + UtahNum
+{
+    /// Render the dataframe with the given `DisplayOptions`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.5], [3.0, std::f64::NAN]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let options = DisplayOptions { precision: Some(2), ..DisplayOptions::default() };
+    /// let table = format!("{}", df.display(options));
+    /// assert_eq!(table, "     a    b\n0 2.00 7.50\n1 3.00  NaN\n");
+    /// ```
+    fn display(&'a self, options: DisplayOptions) -> DataFrameDisplay<'a, T>
+        where T: fmt::Display
+    {
+        DataFrameDisplay {
+            df: self,
+            options,
+        }
+    }
+}
+
+/// Positions to render along an axis of length `n`. `None` marks the elided middle.
+fn visible(n: usize, max: usize) -> Vec<Option<usize>> {
+    if n <= max {
+        return (0..n).map(Some).collect();
+    }
+    let head = max.div_ceil(2);
+    let tail = max / 2;
+    (0..head)
+        .map(Some)
+        .chain(Some(None))
+        .chain((n - tail..n).map(Some))
+        .collect()
+}
+
+impl<'a, T> fmt::Display for DataFrameDisplay<'a, T>
+    where T: UtahNum + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let df = self.df;
+        let (nrows, ncols) = df.data.dim();
+        let rows = visible(nrows, self.options.max_rows);
+        let cols = visible(ncols, self.options.max_cols);
+        let ellipsis = "...".to_string();
+
+        let cell = |i: usize, j: usize| -> String {
+            let x = &df.data[[i, j]];
+            if x.is_empty() {
+                self.options.na_rep.clone()
+            } else {
+                match self.options.precision {
+                    Some(p) => format!("{:.*}", p, x),
+                    None => format!("{}", x),
+                }
+            }
+        };
+
+        // Render every visible cell up front so that column widths can be measured.
+        let header: Vec<String> = cols.iter()
+            .map(|c| match *c {
+                Some(j) => df.columns[j].clone(),
+                None => ellipsis.clone(),
+            })
+            .collect();
+        let labels: Vec<String> = rows.iter()
+            .map(|r| match *r {
+                Some(i) => df.index[i].clone(),
+                None => ellipsis.clone(),
+            })
+            .collect();
+        let body: Vec<Vec<String>> = rows.iter()
+            .map(|r| {
+                cols.iter()
+                    .map(|c| match (*r, *c) {
+                        (Some(i), Some(j)) => cell(i, j),
+                        _ => ellipsis.clone(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let label_width = labels.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+        let widths: Vec<usize> = header.iter()
+            .enumerate()
+            .map(|(j, h)| {
+                body.iter()
+                    .map(|row| row[j].chars().count())
+                    .chain(Some(h.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(f, "{:w$}", "", w = label_width)?;
+        for (h, w) in header.iter().zip(widths.iter()) {
+            write!(f, " {:>w$}", h, w = *w)?;
+        }
+        writeln!(f)?;
+        for (label, row) in labels.iter().zip(body.iter()) {
+            write!(f, "{:w$}", label, w = label_width)?;
+            for (x, w) in row.iter().zip(widths.iter()) {
+                write!(f, " {:>w$}", x, w = *w)?;
+            }
+            writeln!(f)?;
+        }
+        if nrows > self.options.max_rows || ncols > self.options.max_cols {
+            writeln!(f, "\n[{} rows x {} columns]", nrows, ncols)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for DataFrame<T>
+    where T: UtahNum + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display(DisplayOptions::default()).fmt(f)
+    }
+}
@@ -0,0 +1,133 @@
+//! One-hot encoding of categorical columns.
+use super::*;
+use ndarray::Array;
+
+impl Dummies for DataFrame<InnerType> {
+    /// Replace each of `columns` with one `Int32` column per distinct value, holding 1 in the
+    /// rows with that value and 0 elsewhere. Rows with an empty cell are 0 in every dummy
+    /// column.
+    ///
+    /// The dummy columns are named `<prefix>_<value>`, where the prefixes default to the
+    /// column names, and take the place of the original column, in order of first appearance.
+    /// With `drop_first`, the first value's column is left out.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("red"), InnerType::from(1.0)],
+    ///                [InnerType::from("blue"), InnerType::from(2.0)]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["colour", "x"]).unwrap();
+    /// let dummies = df.get_dummies(&["colour"], None, false).unwrap();
+    /// assert_eq!(dummies.columns, vec!["colour_red", "colour_blue", "x"]);
+    /// assert_eq!(dummies.data[[1, 1]], InnerType::Int32(1));
+    /// let back = dummies.from_dummies("colour", "colour", None).unwrap();
+    /// assert_eq!(back, df);
+    ///
+    /// let dropped = df.get_dummies(&["colour"], Some(&["c"]), true).unwrap();
+    /// assert_eq!(dropped.columns, vec!["c_blue", "x"]);
+    /// assert_eq!(dropped.from_dummies("c", "colour", Some("red")).unwrap(), df);
+    /// ```
+    fn get_dummies(&self,
+                   columns: &[&str],
+                   prefix: Option<&[&str]>,
+                   drop_first: bool)
+                   -> Result<DataFrame<InnerType>> {
+        let prefixes = prefix.unwrap_or(columns);
+        if prefixes.len() != columns.len() {
+            return Err(ErrorKind::ColumnShapeMismatch(columns.len().to_string(),
+                                                      prefixes.len().to_string())
+                .into());
+        }
+        let mut encoded: Vec<Option<(&str, Categorical)>> = vec![None; self.columns.len()];
+        for (c, p) in columns.iter().zip(prefixes.iter()) {
+            encoded[self.column_position(c)?] = Some((p, self.to_categorical(c)?));
+        }
+
+        let nrows = self.index.len();
+        let mut names = Vec::new();
+        let mut values: Vec<Column<InnerType>> = Vec::new();
+        for (j, e) in encoded.iter().enumerate() {
+            match *e {
+                None => {
+                    names.push(self.columns[j].clone());
+                    values.push(self.data.column(j).to_owned());
+                }
+                Some((p, ref cat)) => {
+                    let skip = if drop_first { 1 } else { 0 };
+                    for (code, value) in cat.categories.iter().enumerate().skip(skip) {
+                        names.push(format!("{}_{}", p, value));
+                        values.push(cat.codes
+                            .iter()
+                            .map(|&x| InnerType::Int32((x == code as u32) as i32))
+                            .collect());
+                    }
+                }
+            }
+        }
+
+        let mut data = Vec::with_capacity(nrows * names.len());
+        for i in 0..nrows {
+            data.extend(values.iter().map(|v| v[i].clone()));
+        }
+        Ok(DataFrame {
+            columns: names,
+            data: Array::from_shape_vec((nrows, values.len()), data).unwrap(),
+            index: self.index.clone(),
+        })
+    }
+
+    /// Collapse the dummy columns named `<prefix>_<value>` back into one string column named
+    /// `column`, in the place of the first dummy column. Rows with no dummy set take
+    /// `default`, the value dropped by `drop_first`, or are empty if it isn't given.
+    ///
+    /// Fails with a `CastError` if a row has more than one dummy set.
+    fn from_dummies(&self,
+                    prefix: &str,
+                    column: &str,
+                    default: Option<&str>)
+                    -> Result<DataFrame<InnerType>> {
+        let start = format!("{}_", prefix);
+        let dummies: Vec<usize> = (0..self.columns.len())
+            .filter(|&j| self.columns[j].starts_with(&start))
+            .collect();
+        let first = match dummies.first() {
+            Some(&j) => j,
+            None => return Err(ErrorKind::InvalidColumnName(start).into()),
+        };
+
+        let mut collapsed = Vec::with_capacity(self.index.len());
+        for (i, row) in self.data.outer_iter().enumerate() {
+            let set: Vec<usize> = dummies.iter()
+                .cloned()
+                .filter(|&j| row[j].as_f64().map(|x| x != 0.0).unwrap_or(false))
+                .collect();
+            collapsed.push(match set.len() {
+                0 => default.map(InnerType::from).unwrap_or_default(),
+                1 => InnerType::from(&self.columns[set[0]][start.len()..]),
+                _ => {
+                    return Err(ErrorKind::CastError(self.index[i].clone(),
+                                                    column.to_string(),
+                                                    format!("{} dummy columns are set", set.len()))
+                        .into())
+                }
+            });
+        }
+
+        let keep: Vec<usize> = (0..self.columns.len())
+            .filter(|j| !dummies.contains(j) || *j == first)
+            .collect();
+        let mut data = Vec::with_capacity(self.index.len() * keep.len());
+        for (row, x) in self.data.outer_iter().zip(collapsed) {
+            for &j in keep.iter() {
+                data.push(if j == first { x.clone() } else { row[j].clone() });
+            }
+        }
+        Ok(DataFrame {
+            columns: keep.iter()
+                .map(|&j| if j == first { column.to_string() } else { self.columns[j].clone() })
+                .collect(),
+            data: Array::from_shape_vec((self.index.len(), keep.len()), data).unwrap(),
+            index: self.index.clone(),
+        })
+    }
+}
@@ -19,6 +19,15 @@ impl Empty<i32> for i32 {
     }
 }
 
+impl Empty<i64> for i64 {
+    fn empty() -> i64 {
+        0
+    }
+    fn is_empty(&self) -> bool {
+        *self == 0
+    }
+}
+
 impl Empty<Option<i32>> for Option<i32> {
     fn empty() -> Option<i32> {
         None
@@ -1,6 +1,32 @@
 use super::*;
 use std::iter::Iterator;
-use ndarray::ArrayView1;
+use ndarray::{s, Array, ArrayView1};
+use std::fmt::Display;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::ops::Range;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::index;
+
+/// Mark the members of `groups` that aren't the occurrence of their group picked by `keep`.
+fn mark(groups: &[usize], ngroups: usize, keep: Keep) -> Column<bool> {
+    let mut counts = vec![0; ngroups];
+    for &g in groups.iter() {
+        counts[g] += 1;
+    }
+    let mut seen = vec![0; ngroups];
+    groups.iter()
+        .map(|&g| {
+            seen[g] += 1;
+            match keep {
+                Keep::First => seen[g] > 1,
+                Keep::Last => seen[g] < counts[g],
+                Keep::None => counts[g] > 1,
+            }
+        })
+        .collect()
+}
 
 #[cfg(not(feature = "specialization"))]
 impl<'a, T> Operations<'a, T> for DataFrame<T>
@@ -87,6 +113,130 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
         }
     }
 
+    /// Take a zero-copy view of the rows and columns at the given positions.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.0, 8.0], [3.0, 4.0, 9.0], [2.0, 8.0, 1.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+    /// let view = df.iloc(0..2, 1..3).unwrap();
+    /// assert_eq!(view.shape(), (2, 2));
+    /// assert_eq!(view.columns, vec!["b", "c"]);
+    /// assert_eq!(df.iloc(2..1, 0..3).unwrap().shape(), (0, 3));
+    /// assert!(df.iloc(5..3, 0..3).is_err());
+    /// assert!(df.iloc(0..3, 4..1).is_err());
+    /// ```
+    fn iloc(&'a self, rows: Range<usize>, columns: Range<usize>) -> Result<DataFrameView<'a, T>> {
+        let (nrows, ncols) = self.data.dim();
+        // A reversed range is empty, but its start must still lie within the axis.
+        let last_row = rows.start.max(rows.end);
+        if last_row > nrows {
+            return Err(ErrorKind::OutOfBounds(nrows.to_string(), last_row.to_string()).into());
+        }
+        let last_column = columns.start.max(columns.end);
+        if last_column > ncols {
+            return Err(ErrorKind::OutOfBounds(ncols.to_string(), last_column.to_string()).into());
+        }
+        let rows = if rows.end < rows.start { rows.start..rows.start } else { rows };
+        let columns = if columns.end < columns.start {
+            columns.start..columns.start
+        } else {
+            columns
+        };
+        Ok(DataFrameView {
+            columns: self.columns[columns.clone()].to_vec(),
+            data: self.data.slice(s![rows.clone(), columns]),
+            index: self.index[rows].to_vec(),
+        })
+    }
+
+    /// Take a zero-copy view of the rows and columns matching the given labels.
+    ///
+    /// Label ranges follow Rust semantics: `"a".."f"` excludes `"f"`, while
+    /// `"a"..="f"` includes it.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.0, 8.0], [3.0, 4.0, 9.0], [2.0, 8.0, 1.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a)
+    ///     .index(&["a", "b", "c"]).unwrap()
+    ///     .columns(&["x", "y", "z"]).unwrap();
+    /// let view = df.loc("a"..="b", &["x", "y"]).unwrap();
+    /// assert_eq!(view.index, vec!["a", "b"]);
+    /// assert_eq!(view.columns, vec!["x", "y"]);
+    /// ```
+    fn loc<R, C>(&'a self, rows: R, columns: C) -> Result<DataFrameView<'a, T>>
+        where R: LabelSlice,
+              C: LabelSlice
+    {
+        let rows = rows.positions(&self.index[..])?;
+        let columns = columns.positions(&self.columns[..])?;
+        self.iloc(rows, columns)
+    }
+
+    /// Take a view of the first `n` rows of the dataframe.
+    fn head(&'a self, n: usize) -> DataFrameView<'a, T> {
+        let (nrows, ncols) = self.data.dim();
+        let n = n.min(nrows);
+        self.iloc(0..n, 0..ncols).unwrap()
+    }
+
+    /// Take a view of the last `n` rows of the dataframe.
+    fn tail(&'a self, n: usize) -> DataFrameView<'a, T> {
+        let (nrows, ncols) = self.data.dim();
+        let n = n.min(nrows);
+        self.iloc(nrows - n..nrows, 0..ncols).unwrap()
+    }
+
+    /// Draw a random sample of rows, with or without replacement.
+    ///
+    /// Passing a `seed` makes the sample reproducible.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, 7.0], [3.0, 4.0], [2.0, 8.0], [1.0, 5.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a);
+    /// let s1 = df.sample(SampleSize::Frac(0.5), false, Some(42)).unwrap();
+    /// let s2 = df.sample(SampleSize::Count(2), false, Some(42)).unwrap();
+    /// assert_eq!(s1, s2);
+    /// ```
+    fn sample(&'a self, size: SampleSize, replace: bool, seed: Option<u64>) -> Result<DataFrame<T>> {
+        let nrows = self.data.dim().0;
+        let n = match size {
+            SampleSize::Count(n) => n,
+            SampleSize::Frac(f) => {
+                if f.is_nan() || f < 0.0 {
+                    return Err(ErrorKind::InvalidSample(f.to_string()).into());
+                }
+                (f * nrows as f64).round() as usize
+            }
+        };
+        if !replace && n > nrows {
+            return Err(ErrorKind::InvalidSample(format!("cannot take {} of {} rows without \
+                                                         replacement",
+                                                        n,
+                                                        nrows))
+                .into());
+        }
+        if replace && n > 0 && nrows == 0 {
+            return Err(ErrorKind::InvalidSample("cannot sample from an empty dataframe".into())
+                .into());
+        }
+        let mut rng = match seed {
+            Some(s) => StdRng::seed_from_u64(s),
+            None => StdRng::from_entropy(),
+        };
+        let rows: Vec<usize> = if replace {
+            (0..n).map(|_| rng.gen_range(0, nrows)).collect()
+        } else {
+            index::sample(&mut rng, nrows, n).into_vec()
+        };
+        Ok(self.take_rows(&rows[..]))
+    }
+
 
     /// Perform an inner left join between two dataframes along the specified `UtahAxis`.
     fn inner_left_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T> {
@@ -229,6 +379,503 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
         }
     }
+
+    /// Count the occurrences of each distinct value of a column, as a dataframe indexed by the
+    /// values with a single `count` column, or `proportion` with `normalize`.
+    ///
+    /// Values are listed in order of first appearance, or from most to least frequent with
+    /// `sort`. Empty values are left out unless `dropna` is false, in which case they are
+    /// counted under the label of `T::empty()`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0], [2.0], [2.0], [std::f64::NAN]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a"]).unwrap();
+    /// let counts = df.value_counts("a", false, true, true).unwrap();
+    /// assert_eq!(counts.index, vec!["2", "1"]);
+    /// assert_eq!(counts.data, arr2(&[[2.0], [1.0]]));
+    /// let shares = df.value_counts("a", true, false, false).unwrap();
+    /// assert_eq!(shares.columns, vec!["proportion"]);
+    /// assert_eq!(shares.index, vec!["1", "2", "NaN"]);
+    /// assert_eq!(shares.data, arr2(&[[0.25], [0.5], [0.25]]));
+    /// ```
+    fn value_counts(&'a self,
+                    column: &str,
+                    normalize: bool,
+                    sort: bool,
+                    dropna: bool)
+                    -> Result<DataFrame<f64>>
+        where T: Display
+    {
+        let values = self.data.column(self.column_position(column)?);
+        let mut counts: Vec<(String, usize)> = distinct(values.iter())
+            .into_iter()
+            .map(|(x, n)| (x.to_string(), n))
+            .collect();
+        let missing = values.iter().filter(|x| x.is_empty()).count();
+        if !dropna && missing > 0 {
+            counts.push((T::empty().to_string(), missing));
+        }
+        if sort {
+            counts.sort_by_key(|c| Reverse(c.1));
+        }
+        let total: usize = counts.iter().map(|c| c.1).sum();
+        let data: Vec<f64> = counts.iter()
+            .map(|&(_, n)| if normalize { n as f64 / total as f64 } else { n as f64 })
+            .collect();
+        let name = if normalize { "proportion" } else { "count" };
+        Ok(DataFrame {
+            columns: vec![name.to_string()],
+            data: Array::from_shape_vec((counts.len(), 1), data).unwrap(),
+            index: counts.into_iter().map(|c| c.0).collect(),
+        })
+    }
+
+    /// Get the distinct non-empty values of a column, in order of first appearance.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[3.0], [1.0], [3.0], [std::f64::NAN]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a"]).unwrap();
+    /// assert_eq!(df.unique("a").unwrap(), vec![3.0, 1.0]);
+    /// ```
+    fn unique(&'a self, column: &str) -> Result<Vec<T>> {
+        let values = self.data.column(self.column_position(column)?);
+        Ok(distinct(values.iter()).into_iter().map(|(x, _)| x).collect())
+    }
+
+    /// Count the distinct non-empty values along the specified `UtahAxis`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 5.0], [1.0, 6.0], [2.0, std::f64::NAN]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let n = df.nunique(UtahAxis::Column).as_df().unwrap();
+    /// assert_eq!(n.data, arr2(&[[2.0, 2.0]]));
+    /// ```
+    fn nunique(&'a self, axis: UtahAxis) -> NUniqueIter<'a, T> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => NUnique::new(self.df_iter(UtahAxis::Row), index, UtahAxis::Row),
+            UtahAxis::Column => {
+                NUnique::new(self.df_iter(UtahAxis::Column), columns, UtahAxis::Column)
+            }
+        }
+    }
+
+    /// Return a copy of the dataframe with `values` as the column `name`, replacing the column
+    /// if it exists and appending it otherwise.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0]])).columns(&["a"]).unwrap();
+    /// let df = df.with_column("b", arr1(&[3.0, 4.0])).unwrap();
+    /// assert_eq!(df.columns, vec!["a", "b"]);
+    /// assert_eq!(df.data, arr2(&[[1.0, 3.0], [2.0, 4.0]]));
+    /// ```
+    fn with_column(&'a self, name: &str, values: Column<T>) -> Result<DataFrame<T>> {
+        if values.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     values.len().to_string())
+                .into());
+        }
+        let mut df = self.clone();
+        match self.columns.iter().position(|c| c == name) {
+            Some(j) => df.data.column_mut(j).assign(&values),
+            None => {
+                let (nrows, ncols) = self.data.dim();
+                let mut c = Vec::with_capacity(nrows * (ncols + 1));
+                for (row, x) in self.data.outer_iter().zip(values.iter()) {
+                    c.extend(row.iter().cloned());
+                    c.push(x.clone());
+                }
+                df.data = Array::from_shape_vec((nrows, ncols + 1), c).unwrap();
+                df.columns.push(name.to_string());
+            }
+        }
+        Ok(df)
+    }
+
+    /// Keep the rows where `mask` is true.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0], [3.0]]));
+    /// let kept = df.filter(&arr1(&[true, false, true])).unwrap();
+    /// assert_eq!(kept.index, vec!["0", "2"]);
+    /// ```
+    fn filter(&'a self, mask: &Column<bool>) -> Result<DataFrame<T>> {
+        if mask.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     mask.len().to_string())
+                .into());
+        }
+        let rows: Vec<usize> = (0..mask.len()).filter(|&i| mask[i]).collect();
+        Ok(self.take_rows(&rows[..]))
+    }
+
+    /// Split the rows by the category each holds in `by`, which must have one row per row of
+    /// the dataframe. Rows with a missing category are left out.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0], [3.0]]));
+    /// let by = Categorical::new(vec![Some("a"), Some("b"), Some("a")]);
+    /// let groups = df.group_by(&by).unwrap();
+    /// assert_eq!(groups[0].0, "a");
+    /// assert_eq!(groups[0].1.data, arr2(&[[1.0], [3.0]]));
+    /// ```
+    fn group_by(&'a self, by: &Categorical) -> Result<Vec<(String, DataFrame<T>)>> {
+        if by.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     by.len().to_string())
+                .into());
+        }
+        Ok(by.groups()
+            .into_iter()
+            .map(|(name, rows)| (name, self.take_rows(&rows[..])))
+            .collect())
+    }
+
+    /// Join the rows of two dataframes whose categories in `left_on` and `right_on` match,
+    /// keeping the left index and placing the right columns after the left ones.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let left : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0]])).columns(&["x"]).unwrap();
+    /// let right : DataFrame<f64> = DataFrame::new(arr2(&[[10.0], [20.0]])).columns(&["y"]).unwrap();
+    /// let left_on = Categorical::new(vec![Some("a"), Some("b")]);
+    /// let right_on = Categorical::new(vec![Some("b"), Some("c")]);
+    /// let joined = left.join_on(&right, &left_on, &right_on).unwrap();
+    /// assert_eq!(joined.columns, vec!["x", "y"]);
+    /// assert_eq!(joined.data, arr2(&[[2.0, 10.0]]));
+    /// ```
+    fn join_on(&'a self,
+               other: &DataFrame<T>,
+               left_on: &Categorical,
+               right_on: &Categorical)
+               -> Result<DataFrame<T>> {
+        if left_on.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     left_on.len().to_string())
+                .into());
+        }
+        if right_on.len() != other.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(other.index.len().to_string(),
+                                                     right_on.len().to_string())
+                .into());
+        }
+        let pairs = left_on.join(right_on);
+        let ncols = self.columns.len() + other.columns.len();
+        let mut values = Vec::with_capacity(pairs.len() * ncols);
+        for &(i, j) in pairs.iter() {
+            values.extend(self.data.row(i).iter().cloned());
+            values.extend(other.data.row(j).iter().cloned());
+        }
+        Ok(DataFrame {
+            columns: self.columns.iter().chain(other.columns.iter()).cloned().collect(),
+            data: Array::from_shape_vec((pairs.len(), ncols), values).unwrap(),
+            index: pairs.iter().map(|&(i, _)| self.index[i].clone()).collect(),
+        })
+    }
+
+    /// Mark the rows that repeat an earlier or later row across the columns in `subset`, or
+    /// across every column if it isn't given. Cells are compared by their `Debug` text, and
+    /// empty values are equal to each other.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[1.0, 2.0], [1.0, 3.0], [1.0, 2.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// assert_eq!(df.duplicated(None, Keep::First).unwrap(), arr1(&[false, false, true]));
+    /// assert_eq!(df.duplicated(None, Keep::Last).unwrap(), arr1(&[true, false, false]));
+    /// assert_eq!(df.duplicated(Some(&["a"]), Keep::None).unwrap(), arr1(&[true, true, true]));
+    /// ```
+    fn duplicated(&'a self, subset: Option<&[&str]>, keep: Keep) -> Result<Column<bool>> {
+        let positions: Vec<usize> = match subset {
+            Some(names) => names.iter().map(|c| self.column_position(c)).collect::<Result<_>>()?,
+            None => (0..self.columns.len()).collect(),
+        };
+        // `T` has no `Hash` bound, so rows are keyed on the text of their cells.
+        let mut lookup: HashMap<Vec<Option<String>>, usize> = HashMap::new();
+        let groups: Vec<usize> = self.data
+            .outer_iter()
+            .map(|row| {
+                let key = positions.iter()
+                    .map(|&j| if row[j].is_empty() { None } else { Some(format!("{:?}", row[j])) })
+                    .collect();
+                let next = lookup.len();
+                *lookup.entry(key).or_insert(next)
+            })
+            .collect();
+        Ok(mark(&groups[..], lookup.len(), keep))
+    }
+
+    /// Remove the rows marked by `duplicated`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 2.0], [1.0, 3.0], [1.0, 2.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let unique = df.drop_duplicates(None, Keep::Last).unwrap();
+    /// assert_eq!(unique.index, vec!["1", "2"]);
+    /// ```
+    fn drop_duplicates(&'a self, subset: Option<&[&str]>, keep: Keep) -> Result<DataFrame<T>> {
+        let mask = self.duplicated(subset, keep)?;
+        let rows: Vec<usize> = (0..mask.len()).filter(|&i| !mask[i]).collect();
+        Ok(self.take_rows(&rows[..]))
+    }
+
+    /// Mark the rows whose index label repeats that of another row.
+    ///
+    /// Joins match rows by label, so a label held by several rows of the right-hand dataframe
+    /// pairs each matching left row with all of them.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0], [3.0]]))
+    ///     .index(&["a", "b", "a"])
+    ///     .unwrap();
+    /// assert_eq!(df.duplicated_index(Keep::First), arr1(&[false, false, true]));
+    /// ```
+    fn duplicated_index(&'a self, keep: Keep) -> Column<bool> {
+        let mut lookup: HashMap<&str, usize> = HashMap::new();
+        let groups: Vec<usize> = self.index
+            .iter()
+            .map(|label| {
+                let next = lookup.len();
+                *lookup.entry(label.as_str()).or_insert(next)
+            })
+            .collect();
+        mark(&groups[..], lookup.len(), keep)
+    }
+
+    /// Move the column `column` into the index, using the text of its values as labels.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 10.0], [2.0, 20.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["id", "x"]).unwrap();
+    /// let df = df.set_index("id").unwrap();
+    /// assert_eq!(df.index, vec!["1", "2"]);
+    /// assert_eq!(df.columns, vec!["x"]);
+    /// let back = df.reset_index("id").unwrap();
+    /// assert_eq!(back.columns, vec!["id", "x"]);
+    /// assert_eq!(back.index, vec!["0", "1"]);
+    /// assert_eq!(back.data, arr2(&[[1.0, 10.0], [2.0, 20.0]]));
+    /// ```
+    fn set_index(&'a self, column: &str) -> Result<DataFrame<T>>
+        where T: Display
+    {
+        let j = self.column_position(column)?;
+        let (nrows, ncols) = self.data.dim();
+        let mut c = Vec::with_capacity(nrows * (ncols - 1));
+        for row in self.data.outer_iter() {
+            c.extend(row.iter().enumerate().filter(|&(k, _)| k != j).map(|(_, x)| x.clone()));
+        }
+        Ok(DataFrame {
+            columns: self.columns
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k != j)
+                .map(|(_, x)| x.clone())
+                .collect(),
+            data: Array::from_shape_vec((nrows, ncols - 1), c).unwrap(),
+            index: self.data.column(j).iter().map(|x| x.to_string()).collect(),
+        })
+    }
+
+    /// Move the index into a new first column named `name`, parsing each label as a value,
+    /// and number the rows from zero.
+    ///
+    /// Fails with a `CastError` if a label can't be read as a value, and with a
+    /// `DuplicateLabel` if there already is a column named `name`.
+    fn reset_index(&'a self, name: &str) -> Result<DataFrame<T>>
+        where T: CastFrom<InnerType>
+    {
+        if self.columns.iter().any(|c| c == name) {
+            return Err(ErrorKind::DuplicateLabel(name.to_string()).into());
+        }
+        let (nrows, ncols) = self.data.dim();
+        let mut c = Vec::with_capacity(nrows * (ncols + 1));
+        for (row, label) in self.data.outer_iter().zip(self.index.iter()) {
+            match T::try_cast_from(&InnerType::from(label.as_str())) {
+                Some(x) => c.push(x),
+                None => {
+                    return Err(ErrorKind::CastError(label.clone(),
+                                                    name.to_string(),
+                                                    "label is not a value of the column type"
+                                                        .to_string())
+                        .into())
+                }
+            }
+            c.extend(row.iter().cloned());
+        }
+        Ok(DataFrame {
+            columns: Some(name.to_string())
+                .into_iter()
+                .chain(self.columns.iter().cloned())
+                .collect(),
+            data: Array::from_shape_vec((nrows, ncols + 1), c).unwrap(),
+            index: (0..nrows).map(|i| i.to_string()).collect(),
+        })
+    }
+
+    /// Rename the index labels or columns along the specified `UtahAxis`. Labels missing from
+    /// `mapping` are kept, and keys of `mapping` that aren't labels are ignored.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// use std::collections::HashMap;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0, 2.0]])).columns(&["a", "b"]).unwrap();
+    /// let mapping : HashMap<&str, &str> = [("a", "x")].iter().cloned().collect();
+    /// assert_eq!(df.rename(&mapping, UtahAxis::Column).columns, vec!["x", "b"]);
+    /// ```
+    fn rename(&'a self, mapping: &HashMap<&str, &str>, axis: UtahAxis) -> DataFrame<T> {
+        let relabel = |labels: &[String]| -> Vec<String> {
+            labels.iter()
+                .map(|x| match mapping.get(x.as_str()) {
+                    Some(y) => y.to_string(),
+                    None => x.clone(),
+                })
+                .collect()
+        };
+        let mut df = self.clone();
+        match axis {
+            UtahAxis::Row => df.index = relabel(&self.index[..]),
+            UtahAxis::Column => df.columns = relabel(&self.columns[..]),
+        }
+        df
+    }
+
+    /// Conform the rows to `labels`, in that order. Rows with a label the dataframe doesn't
+    /// have are filled with `fill`, or with empty values if it isn't given.
+    ///
+    /// Fails with a `DuplicateLabel` if the dataframe's index repeats a label, since the row
+    /// to take would be ambiguous.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0]])).index(&["a", "b"]).unwrap();
+    /// let df = df.reindex(&["b", "c", "a"], None).unwrap();
+    /// assert_eq!(df.index, vec!["b", "c", "a"]);
+    /// assert_eq!(df.data[[0, 0]], 2.0);
+    /// assert!(df.data[[1, 0]].is_nan());
+    /// ```
+    fn reindex(&'a self, labels: &[&str], fill: Option<T>) -> Result<DataFrame<T>> {
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for (i, label) in self.index.iter().enumerate() {
+            if positions.insert(label.as_str(), i).is_some() {
+                return Err(ErrorKind::DuplicateLabel(label.clone()).into());
+            }
+        }
+        let fill = fill.unwrap_or_else(T::empty);
+        let ncols = self.columns.len();
+        let mut c = Vec::with_capacity(labels.len() * ncols);
+        for label in labels.iter() {
+            match positions.get(label) {
+                Some(&i) => c.extend(self.data.row(i).iter().cloned()),
+                None => c.extend((0..ncols).map(|_| fill.clone())),
+            }
+        }
+        Ok(DataFrame {
+            columns: self.columns.clone(),
+            data: Array::from_shape_vec((labels.len(), ncols), c).unwrap(),
+            index: labels.iter().map(|x| x.to_string()).collect(),
+        })
+    }
+
+    /// Put the columns in the order of `columns`, which must name each column exactly once.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0, 2.0]])).columns(&["a", "b"]).unwrap();
+    /// let df = df.reorder_columns(&["b", "a"]).unwrap();
+    /// assert_eq!(df.data, arr2(&[[2.0, 1.0]]));
+    /// assert!(df.reorder_columns(&["a"]).is_err());
+    /// ```
+    fn reorder_columns(&'a self, columns: &[&str]) -> Result<DataFrame<T>> {
+        if columns.len() != self.columns.len() {
+            return Err(ErrorKind::ColumnShapeMismatch(self.columns.len().to_string(),
+                                                      columns.len().to_string())
+                .into());
+        }
+        let mut order: Vec<usize> = Vec::with_capacity(columns.len());
+        for c in columns.iter() {
+            let j = self.column_position(c)?;
+            if order.contains(&j) {
+                return Err(ErrorKind::DuplicateLabel(c.to_string()).into());
+            }
+            order.push(j);
+        }
+        let nrows = self.index.len();
+        let mut c = Vec::with_capacity(nrows * order.len());
+        for row in self.data.outer_iter() {
+            c.extend(order.iter().map(|&j| row[j].clone()));
+        }
+        Ok(DataFrame {
+            columns: order.iter().map(|&j| self.columns[j].clone()).collect(),
+            data: Array::from_shape_vec((nrows, order.len()), c).unwrap(),
+            index: self.index.clone(),
+        })
+    }
+
+    /// Combine every row (`UtahAxis::Row`) or column (`UtahAxis::Column`) with `values`,
+    /// which must have one element per column or per row respectively.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0, 10.0], [3.0, 30.0]]));
+    /// let centered = df.broadcast(&arr1(&[2.0, 20.0]), UtahAxis::Row, |x, m| x - m).unwrap();
+    /// assert_eq!(centered.data, arr2(&[[-1.0, -10.0], [1.0, 10.0]]));
+    /// let scaled = df.broadcast(&arr1(&[1.0, 10.0]), UtahAxis::Column, |x, s| x / s).unwrap();
+    /// assert_eq!(scaled.data, arr2(&[[1.0, 10.0], [0.3, 3.0]]));
+    /// ```
+    fn broadcast<F>(&'a self, values: &Row<T>, axis: UtahAxis, f: F) -> Result<DataFrame<T>>
+        where F: Fn(T, T) -> T
+    {
+        let (nrows, ncols) = self.data.dim();
+        let expected = match axis {
+            UtahAxis::Row => ncols,
+            UtahAxis::Column => nrows,
+        };
+        if values.len() != expected {
+            return Err(match axis {
+                UtahAxis::Row => {
+                    ErrorKind::ColumnShapeMismatch(ncols.to_string(), values.len().to_string())
+                }
+                UtahAxis::Column => {
+                    ErrorKind::IndexShapeMismatch(nrows.to_string(), values.len().to_string())
+                }
+            }
+                .into());
+        }
+        let mut df = self.clone();
+        for ((i, j), x) in df.data.indexed_iter_mut() {
+            let v = match axis {
+                UtahAxis::Row => values[j].clone(),
+                UtahAxis::Column => values[i].clone(),
+            };
+            *x = f(x.clone(), v);
+        }
+        Ok(df)
+    }
 }
 
 
@@ -0,0 +1,84 @@
+use super::*;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+fn position(labels: &[String], label: &str) -> Result<usize> {
+    match labels.iter().position(|x| x == label) {
+        Some(i) => Ok(i),
+        None => Err(ErrorKind::InvalidLabel(label.to_string()).into()),
+    }
+}
+
+fn bounded(start: usize, end: usize) -> Range<usize> {
+    if end < start { start..start } else { start..end }
+}
+
+/// Select the labels from `start` up to, but excluding, `end`.
+impl LabelSlice for Range<&str> {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let start = position(labels, self.start)?;
+        let end = position(labels, self.end)?;
+        Ok(bounded(start, end))
+    }
+}
+
+/// Select the labels from `start` up to and including `end`.
+impl LabelSlice for RangeInclusive<&str> {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let start = position(labels, self.start())?;
+        let end = position(labels, self.end())?;
+        Ok(bounded(start, end + 1))
+    }
+}
+
+impl LabelSlice for RangeFrom<&str> {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let start = position(labels, self.start)?;
+        Ok(start..labels.len())
+    }
+}
+
+impl LabelSlice for RangeTo<&str> {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let end = position(labels, self.end)?;
+        Ok(0..end)
+    }
+}
+
+impl LabelSlice for RangeToInclusive<&str> {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let end = position(labels, self.end)?;
+        Ok(0..end + 1)
+    }
+}
+
+impl LabelSlice for RangeFull {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        Ok(0..labels.len())
+    }
+}
+
+/// Select an explicit list of labels. Views are zero-copy, so the labels must
+/// name a contiguous run of rows or columns, in order.
+impl<'b> LabelSlice for &'b [&'b str] {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        let mut pos = Vec::with_capacity(self.len());
+        for label in self.iter() {
+            pos.push(position(labels, label)?);
+        }
+        match (pos.first(), pos.last()) {
+            (Some(&start), Some(&end)) => {
+                if pos.iter().enumerate().any(|(i, &p)| p != start + i) {
+                    return Err(ErrorKind::NonContiguousSelection(self.join(", ")).into());
+                }
+                Ok(start..end + 1)
+            }
+            _ => Ok(0..0),
+        }
+    }
+}
+
+impl<'b, const N: usize> LabelSlice for &'b [&'b str; N] {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>> {
+        (&self[..]).positions(labels)
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+fn rng_from(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Group row positions by the value they hold in `column`. Empty values form their own group.
+fn strata<T: UtahNum>(df: &DataFrame<T>, column: &str) -> Result<Vec<Vec<usize>>> {
+    let col = df.column_position(column)?;
+    let mut keys: Vec<T> = Vec::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut empties: Vec<usize> = Vec::new();
+    for (i, x) in df.data.column(col).iter().enumerate() {
+        if x.is_empty() {
+            empties.push(i);
+            continue;
+        }
+        match keys.iter().position(|k| k == x) {
+            Some(g) => groups[g].push(i),
+            None => {
+                keys.push(x.clone());
+                groups.push(vec![i]);
+            }
+        }
+    }
+    if !empties.is_empty() {
+        groups.push(empties);
+    }
+    Ok(groups)
+}
+
+impl<'a, T> Split<'a, T> for DataFrame<T>
+    where T: 'a + UtahNum
+{
+    /// Split the rows into a `(train, test)` pair, putting `test_frac` of the rows in the test set.
+    ///
+    /// When `stratify` names a column, each distinct value of that column is split separately so
+    /// both sets keep its proportions. Rows keep their index labels and original order.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 0.0], [2.0, 1.0], [3.0, 0.0], [4.0, 1.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["x", "y"]).unwrap();
+    /// let (train, test) = df.train_test_split(0.5, Some(7), Some("y")).unwrap();
+    /// assert_eq!((train.data.dim().0, test.data.dim().0), (2, 2));
+    /// ```
+    fn train_test_split(&'a self,
+                        test_frac: f64,
+                        seed: Option<u64>,
+                        stratify: Option<&str>)
+                        -> Result<(DataFrame<T>, DataFrame<T>)> {
+        if !(0.0..=1.0).contains(&test_frac) {
+            return Err(ErrorKind::InvalidSplit(format!("test fraction {} is not between 0 and 1",
+                                                       test_frac))
+                .into());
+        }
+        let groups = match stratify {
+            Some(column) => strata(self, column)?,
+            None => vec![(0..self.index.len()).collect()],
+        };
+        let mut rng = rng_from(seed);
+        let mut train = Vec::new();
+        let mut test = Vec::new();
+        for mut group in groups {
+            group.shuffle(&mut rng);
+            let n = (test_frac * group.len() as f64).round() as usize;
+            test.extend_from_slice(&group[..n]);
+            train.extend_from_slice(&group[n..]);
+        }
+        train.sort_unstable();
+        test.sort_unstable();
+        Ok((self.take_rows(&train[..]), self.take_rows(&test[..])))
+    }
+
+    /// Split the rows into `k` folds and iterate over the `(train, test)` pairs.
+    ///
+    /// Rows are shuffled before being assigned to folds. Passing a `seed` makes the folds
+    /// reproducible.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0], [2.0], [3.0], [4.0], [5.0], [6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a);
+    /// for (train, test) in df.kfold(3, Some(1)).unwrap() {
+    ///     assert_eq!((train.data.dim().0, test.data.dim().0), (4, 2));
+    /// }
+    /// ```
+    fn kfold(&'a self, k: usize, seed: Option<u64>) -> Result<KFold<'a, T>> {
+        let nrows = self.index.len();
+        if k < 2 || k > nrows {
+            return Err(ErrorKind::InvalidSplit(format!("cannot make {} folds from {} rows",
+                                                       k,
+                                                       nrows))
+                .into());
+        }
+        let mut rows: Vec<usize> = (0..nrows).collect();
+        rows.shuffle(&mut rng_from(seed));
+        let mut folds: Vec<Vec<usize>> = vec![Vec::new(); k];
+        for (i, r) in rows.into_iter().enumerate() {
+            folds[i % k].push(r);
+        }
+        for fold in folds.iter_mut() {
+            fold.sort_unstable();
+        }
+        Ok(KFold::new(self, folds))
+    }
+}
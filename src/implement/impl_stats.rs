@@ -0,0 +1,152 @@
+use super::*;
+use std::cmp::Ordering;
+use ndarray::Array;
+
+impl<'a, T> Stats<'a, T> for DataFrame<T>
+    where T: 'a + UtahNum,
+          f64: CastFrom<T>
+{
+    /// Compute the correlation between every pair of columns, as a square dataframe indexed
+    /// by the column names.
+    ///
+    /// Each pair uses the rows where both columns hold a number, so a missing value only
+    /// removes its row from the pairs it takes part in. Pairs with fewer than two such rows,
+    /// or with a constant column, give an empty cell.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 2.0, 9.0],
+    ///                [2.0, 4.0, 1.0],
+    ///                [3.0, 6.0, std::f64::NAN],
+    ///                [4.0, 8.5, 0.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+    /// let corr = df.corr(CorrMethod::Spearman);
+    /// assert_eq!(corr.index, vec!["a", "b", "c"]);
+    /// assert_eq!(corr.data[[0, 1]], 1.0);
+    /// assert_eq!(corr.data[[0, 2]], -1.0);
+    /// ```
+    fn corr(&'a self, method: CorrMethod) -> DataFrame<f64> {
+        pairwise(self, |x, y| match method {
+            CorrMethod::Pearson => pearson(x, y),
+            CorrMethod::Spearman => pearson(&ranks(x)[..], &ranks(y)[..]),
+            CorrMethod::Kendall => kendall(x, y),
+        })
+    }
+
+    /// Compute the covariance between every pair of columns, as a square dataframe indexed
+    /// by the column names, dividing by the number of complete rows less `ddof`.
+    ///
+    /// As with `corr`, each pair uses the rows where both columns hold a number.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// assert_eq!(df.cov(1).data, arr2(&[[1.0, 2.0], [2.0, 4.0]]));
+    /// ```
+    fn cov(&'a self, ddof: usize) -> DataFrame<f64> {
+        pairwise(self, |x, y| {
+            if x.len() <= ddof {
+                return f64::empty();
+            }
+            let (mx, my) = (mean(x), mean(y));
+            let s: f64 = x.iter().zip(y.iter()).map(|(a, b)| (a - mx) * (b - my)).sum();
+            s / (x.len() - ddof) as f64
+        })
+    }
+}
+
+/// Apply `f` to the pairwise-complete values of every pair of columns of `df`.
+fn pairwise<T, F>(df: &DataFrame<T>, f: F) -> DataFrame<f64>
+    where T: UtahNum,
+          f64: CastFrom<T>,
+          F: Fn(&[f64], &[f64]) -> f64
+{
+    let values = df.data.map(f64::cast_from);
+    let n = df.columns.len();
+    let mut out = Array::from_elem((n, n), f64::empty());
+    for a in 0..n {
+        for b in a..n {
+            let (x, y): (Vec<f64>, Vec<f64>) = values.column(a)
+                .iter()
+                .zip(values.column(b).iter())
+                .filter(|&(x, y)| !x.is_nan() && !y.is_nan())
+                .map(|(x, y)| (*x, *y))
+                .unzip();
+            let r = f(&x[..], &y[..]);
+            out[[a, b]] = r;
+            out[[b, a]] = r;
+        }
+    }
+    DataFrame {
+        columns: df.columns.clone(),
+        data: out,
+        index: df.columns.clone(),
+    }
+}
+
+fn mean(x: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() < 2 {
+        return f64::empty();
+    }
+    let (mx, my) = (mean(x), mean(y));
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y.iter()) {
+        sxy += (a - mx) * (b - my);
+        sxx += (a - mx) * (a - mx);
+        syy += (b - my) * (b - my);
+    }
+    if sxx == 0.0 || syy == 0.0 {
+        return f64::empty();
+    }
+    sxy / (sxx * syy).sqrt()
+}
+
+/// The rank of each value, from 1, with tied values given the average of their ranks.
+fn ranks(x: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap_or(Ordering::Equal));
+    let mut ranks = vec![0.0; x.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && x[order[end]] == x[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in order[start..end].iter() {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let (mut concordant, mut discordant, mut tied_x, mut tied_y) = (0i64, 0i64, 0i64, 0i64);
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            let dx = x[i].partial_cmp(&x[j]).unwrap_or(Ordering::Equal);
+            let dy = y[i].partial_cmp(&y[j]).unwrap_or(Ordering::Equal);
+            match (dx, dy) {
+                (Ordering::Equal, Ordering::Equal) => {}
+                (Ordering::Equal, _) => tied_x += 1,
+                (_, Ordering::Equal) => tied_y += 1,
+                _ if dx == dy => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+    let n0 = (concordant + discordant + tied_x) as f64;
+    let n1 = (concordant + discordant + tied_y) as f64;
+    if n0 == 0.0 || n1 == 0.0 {
+        return f64::empty();
+    }
+    (concordant - discordant) as f64 / (n0 * n1).sqrt()
+}
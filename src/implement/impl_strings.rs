@@ -0,0 +1,22 @@
+use super::*;
+
+impl<'a> StringColumns<'a> for DataFrame<InnerType> {
+    /// Access the string operations of a column.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from(" Apple ")], [InnerType::from("banana")]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["fruit"]).unwrap();
+    /// let clean = df.with_column("fruit", df.str("fruit").unwrap().strip()).unwrap();
+    /// let clean = clean.with_column("fruit", clean.str("fruit").unwrap().to_lowercase()).unwrap();
+    /// let apples = clean.filter(&clean.str("fruit").unwrap().starts_with("app")).unwrap();
+    /// assert_eq!(apples.data[[0, 0]], InnerType::from("apple"));
+    /// ```
+    fn str(&'a self, column: &str) -> Result<StringColumn<'a>> {
+        Ok(StringColumn {
+            df: self,
+            column: self.column_position(column)?,
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use chrono::{NaiveDateTime, TimeDelta};
+use ndarray::Array;
+
+impl<'a, T> TimeSeries<'a, T> for DataFrame<T>
+    where T: 'a + UtahNum
+{
+    /// Bucket the rows into fixed intervals of `freq`, written as in `parse_duration`
+    /// (e.g. `"1h"` or `"15min"`). The index labels must be timestamps.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0], [2.0], [4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a)
+    ///     .index(&["2020-01-01 10:10:00", "2020-01-01 10:50:00", "2020-01-01 11:00:00"])
+    ///     .unwrap();
+    /// let hourly = df.resample("1h").unwrap().mean().unwrap();
+    /// assert_eq!(hourly.data, arr2(&[[1.5], [4.0]]));
+    /// assert!(df.resample("fortnightly").is_err());
+    /// assert!(df.resample("0s").is_err());
+    ///
+    /// let long : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0]]))
+    ///     .index(&["1600-01-01 00:00:00", "2020-01-01 00:00:00"])
+    ///     .unwrap();
+    /// assert_eq!(long.resample("1d").unwrap().buckets, 153403);
+    /// assert!(long.resample("1ns").is_err());
+    /// ```
+    fn resample(&'a self, freq: &str) -> Result<Resample<'a, T>> {
+        let step = match parse_duration(freq) {
+            Some(d) if d > TimeDelta::zero() => d,
+            _ => return Err(ErrorKind::InvalidFrequency(freq.to_string()).into()),
+        };
+        let times = self.datetime_index()?;
+        Resample::new(self, times, step)
+    }
+
+    /// Conform the dataframe to a regular grid of timestamps spaced by `freq`, keeping the rows
+    /// that fall exactly on the grid and leaving the other grid points empty.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0], [3.0]]))
+    ///     .index(&["2020-01-01 10:00:00", "2020-01-01 10:20:00", "2020-01-01 11:00:00"])
+    ///     .unwrap();
+    /// let regular = df.asfreq("30min").unwrap();
+    /// assert_eq!(regular.index.len(), 3);
+    /// assert_eq!(regular.data[[0, 0]], 1.0);
+    /// assert!(regular.data[[1, 0]].is_nan());
+    /// assert_eq!(regular.data[[2, 0]], 3.0);
+    /// ```
+    fn asfreq(&'a self, freq: &str) -> Result<DataFrame<T>> {
+        Ok(self.resample(freq)?.asfreq())
+    }
+
+    /// Move the values down by `periods` rows (or up, if negative), keeping the index. Rows
+    /// shifted in from outside the dataframe are empty.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[1.0], [2.0], [3.0]]));
+    /// let lagged = df.shift(1);
+    /// assert!(lagged.data[[0, 0]].is_nan());
+    /// assert_eq!(lagged.data[[2, 0]], 2.0);
+    /// assert_eq!(df.shift(-1).data[[0, 0]], 2.0);
+    /// ```
+    fn shift(&'a self, periods: isize) -> DataFrame<T> {
+        let (nrows, ncols) = self.data.dim();
+        let mut values = Vec::with_capacity(nrows * ncols);
+        for i in 0..nrows as isize {
+            let from = i - periods;
+            if from >= 0 && from < nrows as isize {
+                values.extend(self.data.row(from as usize).iter().cloned());
+            } else {
+                values.extend((0..ncols).map(|_| T::empty()));
+            }
+        }
+        DataFrame {
+            columns: self.columns.clone(),
+            data: Array::from_shape_vec((nrows, ncols), values).unwrap(),
+            index: self.index.clone(),
+        }
+    }
+
+    /// Read the index labels as timestamps, or fail with `InvalidLabel` on the first label
+    /// that isn't one.
+    fn datetime_index(&'a self) -> Result<Vec<NaiveDateTime>> {
+        self.index
+            .iter()
+            .map(|label| match InnerType::parse(label, DType::DateTime) {
+                Some(InnerType::DateTime(t)) => Ok(t),
+                _ => Err(ErrorKind::InvalidLabel(label.clone()).into()),
+            })
+            .collect()
+    }
+}
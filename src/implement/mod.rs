@@ -6,7 +6,18 @@ pub use self::impl_ops_f64_string::*;
 pub use self::impl_ops_generic::*;
 
 pub mod impl_constructor_generic;
+pub mod impl_display;
+pub mod impl_dummies;
 #[cfg(features = "specialization")]
 pub mod impl_ops_f64_string;
 pub mod impl_ops_generic;
+pub mod impl_arith;
+pub mod impl_cast;
+pub mod impl_categorical;
+pub mod impl_datetime;
 pub mod impl_empty;
+pub mod impl_slice;
+pub mod impl_split;
+pub mod impl_stats;
+pub mod impl_strings;
+pub mod impl_timeseries;
@@ -0,0 +1,461 @@
+//! Utah mixed-type elements.
+//!
+//! A `DataFrame<InnerType>` holds cells of different types side by side, which is how files
+//! with heterogeneous columns are represented.
+use super::*;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use num::traits::{One, Zero};
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+#[cfg(feature = "serde-1")]
+use serde_derive::{Deserialize, Serialize};
+
+/// A single cell of a mixed-type dataframe.
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub enum InnerType {
+    Float(f64),
+    Int32(i32),
+    Int64(i64),
+    Bool(bool),
+    Str(String),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    Duration(TimeDelta),
+    #[default]
+    Empty,
+}
+
+/// The type of the values held by a column.
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub enum DType {
+    Float,
+    Int32,
+    Int64,
+    Bool,
+    Str,
+    Date,
+    DateTime,
+    Duration,
+}
+
+/// The name, type and nullability of a column.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub struct Field {
+    pub name: String,
+    pub dtype: DType,
+    pub nullable: bool,
+}
+
+impl Field {
+    pub fn new(name: &str, dtype: DType, nullable: bool) -> Field {
+        Field {
+            name: name.to_string(),
+            dtype,
+            nullable,
+        }
+    }
+}
+
+impl InnerType {
+    /// The type of the value, or `None` for an empty cell.
+    pub fn dtype(&self) -> Option<DType> {
+        match *self {
+            InnerType::Float(x) if x.is_nan() => None,
+            InnerType::Float(_) => Some(DType::Float),
+            InnerType::Int32(_) => Some(DType::Int32),
+            InnerType::Int64(_) => Some(DType::Int64),
+            InnerType::Bool(_) => Some(DType::Bool),
+            InnerType::Str(_) => Some(DType::Str),
+            InnerType::Date(_) => Some(DType::Date),
+            InnerType::DateTime(_) => Some(DType::DateTime),
+            InnerType::Duration(_) => Some(DType::Duration),
+            InnerType::Empty => None,
+        }
+    }
+
+    /// Parse `text` as a value of the given type. Blank text is an empty cell.
+    ///
+    /// Dates are read as `%Y-%m-%d`, timestamps as `%Y-%m-%d %H:%M:%S` (or with a `T`
+    /// separator, and optional fractional seconds), and durations as in `parse_duration`.
+    pub fn parse(text: &str, dtype: DType) -> Option<InnerType> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(InnerType::Empty);
+        }
+        match dtype {
+            DType::Float => text.parse().ok().map(InnerType::Float),
+            DType::Int32 => text.parse().ok().map(InnerType::Int32),
+            DType::Int64 => text.parse().ok().map(InnerType::Int64),
+            DType::Bool => {
+                match text.to_lowercase().as_str() {
+                    "true" => Some(InnerType::Bool(true)),
+                    "false" => Some(InnerType::Bool(false)),
+                    _ => None,
+                }
+            }
+            DType::Str => Some(InnerType::Str(text.to_string())),
+            DType::Date => {
+                NaiveDate::parse_from_str(text, "%Y-%m-%d").ok().map(InnerType::Date)
+            }
+            DType::DateTime => {
+                DATETIME_FORMATS.iter()
+                    .filter_map(|f| NaiveDateTime::parse_from_str(text, f).ok())
+                    .next()
+                    .map(InnerType::DateTime)
+            }
+            DType::Duration => parse_duration(text).map(InnerType::Duration),
+        }
+    }
+
+    /// Parse `text` as a timestamp in the given `strftime` format. Formats without a time of
+    /// day give midnight. Blank text is an empty cell.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let t = InnerType::parse_datetime("03/01/2020 14:30", "%d/%m/%Y %H:%M").unwrap();
+    /// assert_eq!(t, InnerType::parse("2020-01-03 14:30:00", DType::DateTime).unwrap());
+    /// ```
+    pub fn parse_datetime(text: &str, format: &str) -> Option<InnerType> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(InnerType::Empty);
+        }
+        NaiveDateTime::parse_from_str(text, format)
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(text, format).ok().map(|d| d.and_time(Default::default()))
+            })
+            .map(InnerType::DateTime)
+    }
+
+    /// The value as a float, if it is numeric.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            InnerType::Float(x) => Some(x),
+            InnerType::Int32(x) => Some(x as f64),
+            InnerType::Int64(x) => Some(x as f64),
+            _ => None,
+        }
+    }
+
+    /// Convert the value to the given type, or `None` if it can't be represented exactly.
+    ///
+    /// Strings are parsed as the target type, any value can become a string, and empty cells
+    /// stay empty.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// assert_eq!(InnerType::Float(3.0).try_convert(DType::Int64), Some(InnerType::Int64(3)));
+    /// assert_eq!(InnerType::Float(3.5).try_convert(DType::Int64), None);
+    /// assert_eq!(InnerType::from("7").try_convert(DType::Int32), Some(InnerType::Int32(7)));
+    /// ```
+    pub fn try_convert(&self, dtype: DType) -> Option<InnerType> {
+        if self.is_empty() {
+            return Some(InnerType::Empty);
+        }
+        if self.dtype() == Some(dtype) {
+            return Some(self.clone());
+        }
+        match (self, dtype) {
+            (_, DType::Str) => Some(InnerType::Str(self.to_string())),
+            (InnerType::Str(s), _) => InnerType::parse(s, dtype),
+            (&InnerType::Bool(b), DType::Float) => Some(InnerType::Float(b as i32 as f64)),
+            (&InnerType::Bool(b), DType::Int32) => Some(InnerType::Int32(b as i32)),
+            (&InnerType::Bool(b), DType::Int64) => Some(InnerType::Int64(b as i64)),
+            (_, DType::Bool) => {
+                match self.as_f64() {
+                    Some(0.0) => Some(InnerType::Bool(false)),
+                    Some(1.0) => Some(InnerType::Bool(true)),
+                    _ => None,
+                }
+            }
+            (&InnerType::Int64(x), DType::Float) => {
+                if x as f64 as i64 == x { Some(InnerType::Float(x as f64)) } else { None }
+            }
+            (_, DType::Float) => self.as_f64().map(InnerType::Float),
+            (_, DType::Int64) => self.exact_i64().map(InnerType::Int64),
+            (_, DType::Int32) => {
+                self.exact_i64()
+                    .and_then(|x| i32::try_from(x).ok())
+                    .map(InnerType::Int32)
+            }
+            (&InnerType::Date(d), DType::DateTime) => {
+                Some(InnerType::DateTime(d.and_time(Default::default())))
+            }
+            (&InnerType::DateTime(t), DType::Date) if t.time() == Default::default() => {
+                Some(InnerType::Date(t.date()))
+            }
+            (_, DType::Date) | (_, DType::DateTime) | (_, DType::Duration) => None,
+        }
+    }
+
+    /// Convert the value to the given type, truncating floats and saturating integers that
+    /// don't fit. Values that can't be converted at all become empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// assert_eq!(InnerType::Float(3.7).convert(DType::Int32), InnerType::Int32(3));
+    /// assert_eq!(InnerType::from("abc").convert(DType::Float), InnerType::Empty);
+    /// ```
+    pub fn convert(&self, dtype: DType) -> InnerType {
+        if let Some(x) = self.try_convert(dtype) {
+            return x;
+        }
+        if let (&InnerType::DateTime(t), DType::Date) = (self, dtype) {
+            return InnerType::Date(t.date());
+        }
+        let number = match *self {
+            InnerType::Str(ref s) => s.trim().parse::<f64>().ok(),
+            _ => self.as_f64(),
+        };
+        match (number, dtype) {
+            (Some(x), DType::Float) => InnerType::Float(x),
+            (Some(x), DType::Int64) => InnerType::Int64(x as i64),
+            (Some(x), DType::Int32) => InnerType::Int32(x as i32),
+            (Some(x), DType::Bool) => InnerType::Bool(x != 0.0),
+            _ => InnerType::Empty,
+        }
+    }
+
+    /// The value as an integer, if it is an integer or a float with no fractional part.
+    fn exact_i64(&self) -> Option<i64> {
+        match *self {
+            InnerType::Float(x) => {
+                // 2^63 is exactly representable, and is the first float out of range.
+                if x.fract() == 0.0 && x >= i64::MIN as f64 && x < i64::MAX as f64 {
+                    Some(x as i64)
+                } else {
+                    None
+                }
+            }
+            _ => self.as_i64(),
+        }
+    }
+
+    /// The value as an integer, if it is an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            InnerType::Int32(x) => Some(x as i64),
+            InnerType::Int64(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl Empty<InnerType> for InnerType {
+    fn empty() -> InnerType {
+        InnerType::Empty
+    }
+    fn is_empty(&self) -> bool {
+        self.dtype().is_none()
+    }
+}
+
+/// Apply an arithmetic operator, staying integral when both sides are integers.
+/// Any other combination of types yields an empty cell.
+fn arithmetic<F, I>(lhs: InnerType, rhs: InnerType, float: F, int: I) -> InnerType
+    where F: Fn(f64, f64) -> f64,
+          I: Fn(i64, i64) -> Option<i64>
+{
+    match (&lhs, &rhs) {
+        (&InnerType::Int32(a), &InnerType::Int32(b)) => {
+            int(a as i64, b as i64)
+                .map(|x| if x as i32 as i64 == x { InnerType::Int32(x as i32) } else { InnerType::Int64(x) })
+                .unwrap_or(InnerType::Empty)
+        }
+        _ => {
+            match (lhs.as_i64(), rhs.as_i64()) {
+                (Some(a), Some(b)) => int(a, b).map(InnerType::Int64).unwrap_or(InnerType::Empty),
+                _ => {
+                    match (lhs.as_f64(), rhs.as_f64()) {
+                        (Some(a), Some(b)) => InnerType::Float(float(a, b)),
+                        _ => InnerType::Empty,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Wrap an optional result, with `None` (an overflow) as an empty cell.
+fn or_empty<U: Into<InnerType>>(x: Option<U>) -> InnerType {
+    x.map(Into::into).unwrap_or(InnerType::Empty)
+}
+
+impl Add for InnerType {
+    type Output = InnerType;
+    fn add(self, rhs: InnerType) -> InnerType {
+        match (self, rhs) {
+            (InnerType::Str(a), InnerType::Str(b)) => InnerType::Str(a + &b),
+            (InnerType::DateTime(t), InnerType::Duration(d)) |
+            (InnerType::Duration(d), InnerType::DateTime(t)) => or_empty(t.checked_add_signed(d)),
+            (InnerType::Date(t), InnerType::Duration(d)) |
+            (InnerType::Duration(d), InnerType::Date(t)) => or_empty(t.checked_add_signed(d)),
+            (InnerType::Duration(a), InnerType::Duration(b)) => or_empty(a.checked_add(&b)),
+            (lhs, rhs) => arithmetic(lhs, rhs, |a, b| a + b, |a, b| a.checked_add(b)),
+        }
+    }
+}
+
+impl Sub for InnerType {
+    type Output = InnerType;
+    fn sub(self, rhs: InnerType) -> InnerType {
+        match (self, rhs) {
+            (InnerType::DateTime(a), InnerType::DateTime(b)) => {
+                InnerType::Duration(a.signed_duration_since(b))
+            }
+            (InnerType::Date(a), InnerType::Date(b)) => InnerType::Duration(a.signed_duration_since(b)),
+            (InnerType::DateTime(t), InnerType::Duration(d)) => or_empty(t.checked_sub_signed(d)),
+            (InnerType::Date(t), InnerType::Duration(d)) => or_empty(t.checked_sub_signed(d)),
+            (InnerType::Duration(a), InnerType::Duration(b)) => or_empty(a.checked_sub(&b)),
+            (lhs, rhs) => arithmetic(lhs, rhs, |a, b| a - b, |a, b| a.checked_sub(b)),
+        }
+    }
+}
+
+impl Mul for InnerType {
+    type Output = InnerType;
+    fn mul(self, rhs: InnerType) -> InnerType {
+        match (self, rhs) {
+            (InnerType::Duration(d), n) | (n, InnerType::Duration(d)) => {
+                or_empty(n.as_i64().and_then(|n| i32::try_from(n).ok()).and_then(|n| d.checked_mul(n)))
+            }
+            (lhs, rhs) => arithmetic(lhs, rhs, |a, b| a * b, |a, b| a.checked_mul(b)),
+        }
+    }
+}
+
+impl Div for InnerType {
+    type Output = InnerType;
+    fn div(self, rhs: InnerType) -> InnerType {
+        match (self, rhs) {
+            (InnerType::Duration(d), n) => {
+                or_empty(n.as_i64().and_then(|n| i32::try_from(n).ok()).and_then(|n| d.checked_div(n)))
+            }
+            (lhs, rhs) => arithmetic(lhs, rhs, |a, b| a / b, |a, b| a.checked_div(b)),
+        }
+    }
+}
+
+impl Zero for InnerType {
+    fn zero() -> InnerType {
+        InnerType::Int32(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.as_f64() == Some(0.0)
+    }
+}
+
+impl One for InnerType {
+    fn one() -> InnerType {
+        InnerType::Int32(1)
+    }
+}
+
+impl PartialOrd for InnerType {
+    /// Numbers compare by value across numeric types; other values only compare with values
+    /// of the same type.
+    fn partial_cmp(&self, other: &InnerType) -> Option<Ordering> {
+        match (self, other) {
+            (&InnerType::Bool(a), &InnerType::Bool(b)) => a.partial_cmp(&b),
+            (InnerType::Str(a), InnerType::Str(b)) => a.partial_cmp(b),
+            (&InnerType::Date(a), &InnerType::Date(b)) => a.partial_cmp(&b),
+            (&InnerType::DateTime(a), &InnerType::DateTime(b)) => a.partial_cmp(&b),
+            (&InnerType::Date(a), &InnerType::DateTime(b)) => {
+                a.and_time(Default::default()).partial_cmp(&b)
+            }
+            (&InnerType::DateTime(a), &InnerType::Date(b)) => {
+                a.partial_cmp(&b.and_time(Default::default()))
+            }
+            (&InnerType::Duration(a), &InnerType::Duration(b)) => a.partial_cmp(&b),
+            (&InnerType::Empty, &InnerType::Empty) => Some(Ordering::Equal),
+            _ => {
+                match (self.as_i64(), other.as_i64()) {
+                    (Some(a), Some(b)) => a.partial_cmp(&b),
+                    _ => {
+                        match (self.as_f64(), other.as_f64()) {
+                            (Some(a), Some(b)) => a.partial_cmp(&b),
+                            _ => None,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for InnerType {
+    /// Floats honour the formatter's precision; other values ignore it.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InnerType::Float(ref x) => fmt::Display::fmt(x, f),
+            InnerType::Int32(x) => write!(f, "{}", x),
+            InnerType::Int64(x) => write!(f, "{}", x),
+            InnerType::Bool(x) => write!(f, "{}", x),
+            InnerType::Str(ref x) => write!(f, "{}", x),
+            InnerType::Date(x) => write!(f, "{}", x),
+            InnerType::DateTime(x) => write!(f, "{}", x),
+            InnerType::Duration(x) => write!(f, "{}", format_duration(x)),
+            InnerType::Empty => write!(f, "null"),
+        }
+    }
+}
+
+impl From<f64> for InnerType {
+    fn from(x: f64) -> InnerType {
+        InnerType::Float(x)
+    }
+}
+
+impl From<i32> for InnerType {
+    fn from(x: i32) -> InnerType {
+        InnerType::Int32(x)
+    }
+}
+
+impl From<i64> for InnerType {
+    fn from(x: i64) -> InnerType {
+        InnerType::Int64(x)
+    }
+}
+
+impl From<bool> for InnerType {
+    fn from(x: bool) -> InnerType {
+        InnerType::Bool(x)
+    }
+}
+
+impl From<String> for InnerType {
+    fn from(x: String) -> InnerType {
+        InnerType::Str(x)
+    }
+}
+
+impl<'a> From<&'a str> for InnerType {
+    fn from(x: &'a str) -> InnerType {
+        InnerType::Str(x.to_string())
+    }
+}
+
+impl From<NaiveDate> for InnerType {
+    fn from(x: NaiveDate) -> InnerType {
+        InnerType::Date(x)
+    }
+}
+
+impl From<NaiveDateTime> for InnerType {
+    fn from(x: NaiveDateTime) -> InnerType {
+        InnerType::DateTime(x)
+    }
+}
+
+impl From<TimeDelta> for InnerType {
+    fn from(x: TimeDelta) -> InnerType {
+        InnerType::Duration(x)
+    }
+}
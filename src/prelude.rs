@@ -1,3 +1,4 @@
 pub use crate::util::*;
 pub use crate::dataframe::*;
-pub use crate::combinators::*;
\ No newline at end of file
+pub use crate::combinators::*;
+pub use crate::mixedtypes::*;
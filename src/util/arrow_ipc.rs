@@ -0,0 +1,176 @@
+//! Conversion between dataframes and Apache Arrow record batches, and reading and writing
+//! Arrow IPC (Feather v2) files.
+//!
+//! The index is stored as a UTF-8 column named `__index_level_0__`, and null slots map to empty
+//! cells.
+use super::*;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+use ndarray::Array;
+use arrow::array::{Array as ArrowArray, ArrayRef, AsArray, PrimitiveArray, StringArray};
+use arrow::compute::{cast, cast_with_options, concat_batches, CastOptions};
+use arrow::datatypes::{DataType, Field, Float64Type, Int32Type, Schema};
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+
+/// The name of the column holding the dataframe index.
+pub const INDEX_COLUMN: &str = "__index_level_0__";
+
+/// An element type that can be stored in an Arrow array.
+pub trait ArrowElement: UtahNum {
+    fn data_type() -> DataType;
+    fn to_arrow(values: Vec<Option<Self>>) -> ArrayRef;
+    fn from_arrow(array: &dyn ArrowArray) -> Vec<Option<Self>>;
+}
+
+macro_rules! arrow_element {
+    ($t:ty, $arrow:ty, $data_type:expr) => {
+        impl ArrowElement for $t {
+            fn data_type() -> DataType {
+                $data_type
+            }
+            fn to_arrow(values: Vec<Option<$t>>) -> ArrayRef {
+                Arc::new(PrimitiveArray::<$arrow>::from(values))
+            }
+            fn from_arrow(array: &dyn ArrowArray) -> Vec<Option<$t>> {
+                array.as_primitive::<$arrow>().iter().collect()
+            }
+        }
+    };
+}
+
+arrow_element!(f64, Float64Type, DataType::Float64);
+arrow_element!(i32, Int32Type, DataType::Int32);
+
+pub trait ReadArrow<T>
+    where T: ArrowElement
+{
+    fn from_record_batch(batch: &RecordBatch) -> Result<DataFrame<T>>;
+    fn read_feather<P: AsRef<Path>>(path: P) -> Result<DataFrame<T>>;
+}
+
+pub trait WriteArrow<T>
+    where T: ArrowElement
+{
+    fn to_record_batch(&self) -> Result<RecordBatch>;
+    fn write_feather<P: AsRef<Path>>(&self, path: P) -> Result<()>;
+}
+
+impl<T> ReadArrow<T> for DataFrame<T>
+    where T: ArrowElement
+{
+    /// Build a dataframe from a record batch. Columns of other types are cast to `T`, and a
+    /// value that can't be cast, such as text that isn't a number, is an error rather than an
+    /// empty cell.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array, StringArray};
+    /// use arrow::record_batch::RecordBatch;
+    /// let numbers: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+    /// let batch = RecordBatch::try_from_iter(vec![("a", numbers)]).unwrap();
+    /// let df : DataFrame<f64> = DataFrame::from_record_batch(&batch).unwrap();
+    /// assert_eq!(df.data[[0, 0]], 1.0);
+    /// assert!(df.data[[1, 0]].is_nan());
+    ///
+    /// let names: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let batch = RecordBatch::try_from_iter(vec![("name", names)]).unwrap();
+    /// assert!(DataFrame::<f64>::from_record_batch(&batch).is_err());
+    /// ```
+    fn from_record_batch(batch: &RecordBatch) -> Result<DataFrame<T>> {
+        let schema = batch.schema();
+        let nrows = batch.num_rows();
+        let mut index: Vec<String> = (0..nrows).map(|x| x.to_string()).collect();
+        let mut columns = Vec::new();
+        let mut data: Vec<Vec<Option<T>>> = Vec::new();
+        for (field, array) in schema.fields().iter().zip(batch.columns()) {
+            if field.name() == INDEX_COLUMN {
+                let labels = cast(array, &DataType::Utf8)?;
+                index = labels.as_string::<i32>()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| x.map(|l| l.to_string()).unwrap_or_else(|| i.to_string()))
+                    .collect();
+                continue;
+            }
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            let array = cast_with_options(array, &T::data_type(), &options)?;
+            columns.push(field.name().clone());
+            data.push(T::from_arrow(array.as_ref()));
+        }
+
+        let mut values = Vec::with_capacity(nrows * columns.len());
+        for i in 0..nrows {
+            values.extend(data.iter().map(|c| c[i].clone().unwrap_or_else(T::empty)));
+        }
+        let matrix = Array::from_shape_vec((nrows, columns.len()), values).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])?.index(&index[..])
+    }
+
+    /// Read an Arrow IPC (Feather v2) file.
+    fn read_feather<P: AsRef<Path>>(path: P) -> Result<DataFrame<T>> {
+        let reader = FileReader::try_new(File::open(path)?, None)?;
+        let schema = reader.schema();
+        let batches = reader.collect::<::std::result::Result<Vec<_>, _>>()?;
+        let batch = concat_batches(&schema, &batches)?;
+        DataFrame::from_record_batch(&batch)
+    }
+}
+
+impl<T> WriteArrow<T> for DataFrame<T>
+    where T: ArrowElement
+{
+    /// Convert the dataframe into a record batch, with the index as the first column.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, std::f64::NAN], [3.0, 4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let batch = df.to_record_batch().unwrap();
+    /// assert_eq!(batch.num_columns(), 3);
+    /// assert_eq!(batch.column(2).null_count(), 1);
+    /// let back : DataFrame<f64> = DataFrame::from_record_batch(&batch).unwrap();
+    /// assert_eq!(back.columns, df.columns);
+    /// ```
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        let mut fields = vec![Field::new(INDEX_COLUMN, DataType::Utf8, false)];
+        let mut arrays: Vec<ArrayRef> = vec![Arc::new(StringArray::from(self.index.clone()))];
+        for (j, name) in self.columns.iter().enumerate() {
+            let values: Vec<Option<T>> = self.data
+                .column(j)
+                .iter()
+                .map(|x| if x.is_empty() { None } else { Some(x.clone()) })
+                .collect();
+            fields.push(Field::new(name.as_str(), T::data_type(), true));
+            arrays.push(T::to_arrow(values));
+        }
+        Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?)
+    }
+
+    /// Write the dataframe to an Arrow IPC (Feather v2) file.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1, 2], [3, 4]]);
+    /// let df : DataFrame<i32> = DataFrame::new(a).index(&["x", "y"]).unwrap();
+    /// let path = std::env::temp_dir().join("utah2_doc_example.feather");
+    /// df.write_feather(&path).unwrap();
+    /// let back : DataFrame<i32> = DataFrame::read_feather(&path).unwrap();
+    /// assert_eq!(back, df);
+    /// ```
+    fn write_feather<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let batch = self.to_record_batch()?;
+        let mut writer = FileWriter::try_new(File::create(path)?, &batch.schema())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+//! Converting elements between the dataframe element types.
+use super::*;
+use std::convert::TryFrom;
+
+/// A conversion into `Self` from elements of type `U`.
+pub trait CastFrom<U>: Sized {
+    /// Convert, truncating floats and saturating integers that don't fit. Values that can't
+    /// be converted become empty cells.
+    fn cast_from(x: &U) -> Self;
+    /// Convert exactly, or `None` if the value can't be represented. Empty cells of float and
+    /// mixed-type sources become the target type's empty value.
+    fn try_cast_from(x: &U) -> Option<Self>;
+}
+
+macro_rules! cast_identity {
+    ($($t:ty),*) => {
+        $(impl CastFrom<$t> for $t {
+            fn cast_from(x: &$t) -> $t {
+                x.clone()
+            }
+            fn try_cast_from(x: &$t) -> Option<$t> {
+                Some(x.clone())
+            }
+        })*
+    };
+}
+
+cast_identity!(f64, i32, i64, InnerType);
+
+/// Integers widen exactly.
+macro_rules! cast_widen {
+    ($from:ty => $($to:ty),*) => {
+        $(impl CastFrom<$from> for $to {
+            fn cast_from(x: &$from) -> $to {
+                *x as $to
+            }
+            fn try_cast_from(x: &$from) -> Option<$to> {
+                Some(*x as $to)
+            }
+        })*
+    };
+}
+
+cast_widen!(i32 => f64, i64);
+
+impl CastFrom<i64> for f64 {
+    fn cast_from(x: &i64) -> f64 {
+        *x as f64
+    }
+    fn try_cast_from(x: &i64) -> Option<f64> {
+        if *x as f64 as i64 == *x { Some(*x as f64) } else { None }
+    }
+}
+
+impl CastFrom<i64> for i32 {
+    fn cast_from(x: &i64) -> i32 {
+        (*x).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+    fn try_cast_from(x: &i64) -> Option<i32> {
+        i32::try_from(*x).ok()
+    }
+}
+
+/// Floats become integers by truncation, or exactly when they are whole and in range.
+/// Empty (`NaN`) cells become the integer empty value.
+macro_rules! cast_float_to_int {
+    ($($to:ty),*) => {
+        $(impl CastFrom<f64> for $to {
+            fn cast_from(x: &f64) -> $to {
+                // `as` saturates, and maps NaN to zero, the integer empty value.
+                *x as $to
+            }
+            fn try_cast_from(x: &f64) -> Option<$to> {
+                if x.is_nan() {
+                    return Some(<$to>::empty());
+                }
+                // The bounds of both integer types are exactly representable as floats.
+                if x.fract() == 0.0 && *x >= <$to>::MIN as f64 && *x < (<$to>::MAX as f64) + 1.0 {
+                    Some(*x as $to)
+                } else {
+                    None
+                }
+            }
+        })*
+    };
+}
+
+cast_float_to_int!(i32, i64);
+
+impl CastFrom<f64> for InnerType {
+    fn cast_from(x: &f64) -> InnerType {
+        if x.is_nan() { InnerType::Empty } else { InnerType::Float(*x) }
+    }
+    fn try_cast_from(x: &f64) -> Option<InnerType> {
+        Some(InnerType::cast_from(x))
+    }
+}
+
+/// Zero is an ordinary value here, even though it is the integer types' empty value.
+macro_rules! cast_int_to_mixed {
+    ($($from:ty),*) => {
+        $(impl CastFrom<$from> for InnerType {
+            fn cast_from(x: &$from) -> InnerType {
+                InnerType::from(*x)
+            }
+            fn try_cast_from(x: &$from) -> Option<InnerType> {
+                Some(InnerType::from(*x))
+            }
+        })*
+    };
+}
+
+cast_int_to_mixed!(i32, i64);
+
+macro_rules! cast_from_mixed {
+    ($($to:ty, $dtype:expr, $variant:path);*) => {
+        $(impl CastFrom<InnerType> for $to {
+            fn cast_from(x: &InnerType) -> $to {
+                match x.convert($dtype) {
+                    $variant(v) => v,
+                    _ => <$to>::empty(),
+                }
+            }
+            fn try_cast_from(x: &InnerType) -> Option<$to> {
+                match x.try_convert($dtype) {
+                    Some($variant(v)) => Some(v),
+                    Some(InnerType::Empty) => Some(<$to>::empty()),
+                    _ => None,
+                }
+            }
+        })*
+    };
+}
+
+cast_from_mixed!(i32, DType::Int32, InnerType::Int32;
+                 i64, DType::Int64, InnerType::Int64);
+
+impl CastFrom<InnerType> for f64 {
+    fn cast_from(x: &InnerType) -> f64 {
+        match x.convert(DType::Float) {
+            InnerType::Float(v) => v,
+            _ => f64::empty(),
+        }
+    }
+    fn try_cast_from(x: &InnerType) -> Option<f64> {
+        match x.try_convert(DType::Float) {
+            Some(InnerType::Float(v)) => Some(v),
+            Some(InnerType::Empty) => Some(f64::empty()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Dictionary-encoded string columns.
+//!
+//! A `Categorical` stores one `u32` code per row and each distinct string once, so columns
+//! with few distinct values take little memory, and comparing, grouping and joining them
+//! works on the codes rather than the strings.
+use super::*;
+use std::collections::HashMap;
+#[cfg(feature = "serde-1")]
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub struct Categorical {
+    /// The category of each row, as a position in `categories`, or `MISSING`.
+    pub codes: Vec<u32>,
+    /// The distinct values, in order of first appearance.
+    pub categories: Vec<String>,
+}
+
+impl Categorical {
+    /// The code of a row with no value.
+    pub const MISSING: u32 = u32::MAX;
+
+    /// Encode a sequence of optional strings.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let c = Categorical::new(vec![Some("b"), Some("a"), None, Some("b")]);
+    /// assert_eq!(c.categories, vec!["b", "a"]);
+    /// assert_eq!(c.codes, vec![0, 1, Categorical::MISSING, 0]);
+    /// assert_eq!(c.get(3), Some("b"));
+    /// ```
+    pub fn new<I, S>(values: I) -> Categorical
+        where I: IntoIterator<Item = Option<S>>,
+              S: AsRef<str>
+    {
+        let mut lookup: HashMap<String, u32> = HashMap::new();
+        let mut categories = Vec::new();
+        let codes = values.into_iter()
+            .map(|v| match v {
+                Some(s) => {
+                    let s = s.as_ref();
+                    match lookup.get(s) {
+                        Some(&code) => code,
+                        None => {
+                            let code = categories.len() as u32;
+                            lookup.insert(s.to_string(), code);
+                            categories.push(s.to_string());
+                            code
+                        }
+                    }
+                }
+                None => Categorical::MISSING,
+            })
+            .collect();
+        Categorical { codes, categories }
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// The value of row `i`, or `None` if it has no value.
+    pub fn get(&self, i: usize) -> Option<&str> {
+        self.categories.get(self.codes[i] as usize).map(|s| s.as_str())
+    }
+
+    /// The code of a value, if it is one of the categories.
+    pub fn code(&self, value: &str) -> Option<u32> {
+        self.categories.iter().position(|c| c == value).map(|c| c as u32)
+    }
+
+    /// Decode into a string column, with missing rows as empty cells.
+    pub fn to_column(&self) -> Column<InnerType> {
+        (0..self.len())
+            .map(|i| self.get(i).map(InnerType::from).unwrap_or_default())
+            .collect()
+    }
+
+    /// Whether each row holds `value`.
+    pub fn eq_value(&self, value: &str) -> Column<bool> {
+        let code = self.code(value).unwrap_or(Categorical::MISSING);
+        self.codes.iter().map(|&c| c != Categorical::MISSING && c == code).collect()
+    }
+
+    /// The code in `self` of each of `other`'s categories, or `MISSING` where `self` lacks it.
+    fn translate(&self, other: &Categorical) -> Vec<u32> {
+        if self.categories == other.categories {
+            return (0..other.categories.len() as u32).collect();
+        }
+        let lookup: HashMap<&str, u32> = self.categories
+            .iter()
+            .enumerate()
+            .map(|(c, s)| (s.as_str(), c as u32))
+            .collect();
+        other.categories
+            .iter()
+            .map(|s| lookup.get(s.as_str()).cloned().unwrap_or(Categorical::MISSING))
+            .collect()
+    }
+
+    /// Whether each row holds the same value as the matching row of `other`. Missing values
+    /// are never equal. The two columns may have different dictionaries.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr1;
+    /// let a = Categorical::new(vec![Some("x"), Some("y"), None]);
+    /// let b = Categorical::new(vec![Some("y"), Some("y"), None]);
+    /// assert_eq!(a.eq_rows(&b).unwrap(), arr1(&[false, true, false]));
+    /// ```
+    pub fn eq_rows(&self, other: &Categorical) -> Result<Column<bool>> {
+        if self.len() != other.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.len().to_string(),
+                                                     other.len().to_string())
+                .into());
+        }
+        let translate = self.translate(other);
+        Ok(self.codes
+            .iter()
+            .zip(other.codes.iter())
+            .map(|(&a, &b)| {
+                a != Categorical::MISSING && b != Categorical::MISSING &&
+                a == translate[b as usize]
+            })
+            .collect())
+    }
+
+    /// The rows holding each category, in category order. Missing rows belong to no group.
+    pub fn groups(&self) -> Vec<(String, Vec<usize>)> {
+        let mut rows: Vec<Vec<usize>> = vec![Vec::new(); self.categories.len()];
+        for (i, &c) in self.codes.iter().enumerate() {
+            if c != Categorical::MISSING {
+                rows[c as usize].push(i);
+            }
+        }
+        self.categories.iter().cloned().zip(rows).collect()
+    }
+
+    /// The pairs of rows of `self` and `other` holding the same value, ordered by the rows of
+    /// `self` and then of `other`.
+    pub fn join(&self, other: &Categorical) -> Vec<(usize, usize)> {
+        let translate = self.translate(other);
+        let mut right: Vec<Vec<usize>> = vec![Vec::new(); self.categories.len()];
+        for (j, &c) in other.codes.iter().enumerate() {
+            if c != Categorical::MISSING && translate[c as usize] != Categorical::MISSING {
+                right[translate[c as usize] as usize].push(j);
+            }
+        }
+        let mut pairs = Vec::new();
+        for (i, &c) in self.codes.iter().enumerate() {
+            if c != Categorical::MISSING {
+                pairs.extend(right[c as usize].iter().map(|&j| (i, j)));
+            }
+        }
+        pairs
+    }
+}
@@ -0,0 +1,73 @@
+//! Timestamps and durations in mixed-type dataframes.
+//!
+//! Timestamps are stored as `InnerType::DateTime` cells. A dataframe indexed by time keeps its
+//! labels in the `%Y-%m-%d %H:%M:%S` format, which `datetime_index` reads back.
+use chrono::TimeDelta;
+
+/// The formats tried, in order, when reading a timestamp without an explicit format.
+pub const DATETIME_FORMATS: [&str; 4] = ["%Y-%m-%d %H:%M:%S%.f",
+                                         "%Y-%m-%dT%H:%M:%S%.f",
+                                         "%Y-%m-%d %H:%M",
+                                         "%Y-%m-%dT%H:%M"];
+
+const DURATION_UNITS: [(&str, i64); 8] = [("w", 7 * 86_400_000_000_000),
+                                          ("d", 86_400_000_000_000),
+                                          ("h", 3_600_000_000_000),
+                                          ("min", 60_000_000_000),
+                                          ("s", 1_000_000_000),
+                                          ("ms", 1_000_000),
+                                          ("us", 1_000),
+                                          ("ns", 1)];
+
+/// Parse a duration written as a sequence of amounts and units, e.g. `"1h"`, `"90s"` or
+/// `"1d12h"`, with an optional leading `-`. The units are `w`, `d`, `h`, `min`, `s`, `ms`,
+/// `us` and `ns`.
+///
+/// ```
+/// use utah2::prelude::*;
+/// use chrono::TimeDelta;
+/// assert_eq!(parse_duration("1h30min"), Some(TimeDelta::minutes(90)));
+/// assert_eq!(parse_duration("-2d"), Some(TimeDelta::days(-2)));
+/// assert_eq!(parse_duration("5 parsecs"), None);
+/// ```
+pub fn parse_duration(text: &str) -> Option<TimeDelta> {
+    let text = text.trim();
+    let (negative, mut rest) = match text.strip_prefix('-') {
+        Some(r) => (true, r),
+        None => (false, text),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    let mut nanos: i64 = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let amount: i64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let units = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+        let scale = DURATION_UNITS.iter().find(|u| u.0 == &rest[..units])?.1;
+        nanos = nanos.checked_add(amount.checked_mul(scale)?)?;
+        rest = &rest[units..];
+    }
+    Some(TimeDelta::nanoseconds(if negative { -nanos } else { nanos }))
+}
+
+/// Write a duration in the notation read by `parse_duration`, e.g. `"1d2h"`.
+pub fn format_duration(duration: TimeDelta) -> String {
+    if duration.is_zero() {
+        return "0s".to_string();
+    }
+    let sign = if duration < TimeDelta::zero() { "-" } else { "" };
+    let duration = duration.abs();
+    // Split off whole days first, so that long durations don't overflow nanoseconds.
+    let days = duration.num_days();
+    let mut nanos = (duration - TimeDelta::days(days)).num_nanoseconds().unwrap_or(0);
+    let mut parts = if days > 0 { format!("{}d", days) } else { String::new() };
+    for &(unit, scale) in DURATION_UNITS.iter().skip(2) {
+        if nanos >= scale {
+            parts.push_str(&format!("{}{}", nanos / scale, unit));
+            nanos %= scale;
+        }
+    }
+    format!("{}{}", sign, parts)
+}
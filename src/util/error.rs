@@ -12,6 +12,15 @@ error_chain! {
 
 
 
+// Automatic conversions from the errors of the IO backends.
+    foreign_links {
+        Io(::std::io::Error);
+        Regex(::regex::Error);
+        Arrow(::arrow::error::ArrowError) #[cfg(feature = "arrow")];
+        Parquet(::parquet::errors::ParquetError) #[cfg(feature = "parquet")];
+        Sqlite(::rusqlite::Error) #[cfg(feature = "sqlite")];
+    }
+
 // Define additional `ErrorKind` variants. The syntax here is
 // the same as `quick_error!`, but the `from()` and `cause()`
 // syntax is not supported.
@@ -34,6 +43,47 @@ error_chain! {
             display("column shape mismatch. Expected length: {}, Actual length: {}",  expected, actual)
         }
 
+        InvalidLabel(t: String) {
+            description("invalid label")
+            display("invalid label: '{}'", t)
+        }
+        DuplicateLabel(t: String) {
+            description("duplicate label")
+            display("duplicate label: '{}'", t)
+        }
+        OutOfBounds(len: String, requested: String) {
+            description("slice out of bounds.")
+            display("slice out of bounds. Axis length: {}, Requested end: {}", len, requested)
+        }
+        NonContiguousSelection(t: String) {
+            description("labels do not form a contiguous selection.")
+            display("labels do not form a contiguous selection: {}", t)
+        }
+        InvalidSample(t: String) {
+            description("invalid sample size.")
+            display("invalid sample size: {}", t)
+        }
+        InvalidSplit(t: String) {
+            description("invalid split.")
+            display("invalid split: {}", t)
+        }
+        TableExists(t: String) {
+            description("table already exists.")
+            display("table already exists: '{}'", t)
+        }
+        CastError(row: String, column: String, t: String) {
+            description("value cannot be cast.")
+            display("cannot cast value at row '{}', column '{}': {}", row, column, t)
+        }
+        InvalidFrequency(t: String) {
+            description("invalid frequency.")
+            display("invalid frequency: '{}'", t)
+        }
+        SchemaMismatch(t: String) {
+            description("data does not match the schema.")
+            display("schema mismatch: {}", t)
+        }
+
         NoCommonValues {
             description("No common values.")
             display("Join failed. No common values.")
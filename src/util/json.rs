@@ -0,0 +1,232 @@
+//! Reading and writing dataframes as JSON.
+//!
+//! Empty cells are written as `null`, and `null` is read back as an empty cell.
+use super::*;
+use ndarray::Array;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+pub trait ReadJSON<T>
+    where T: UtahNum + DeserializeOwned
+{
+    fn read_json(json: &str, orient: JsonOrient) -> Result<DataFrame<T>>;
+}
+
+pub trait WriteJSON<T>
+    where T: UtahNum + Serialize
+{
+    fn to_json(&self, orient: JsonOrient) -> String;
+}
+
+impl<T> ReadJSON<T> for DataFrame<T>
+    where T: UtahNum + DeserializeOwned
+{
+    /// Read a dataframe from a JSON string laid out according to `orient`.
+    ///
+    /// The `Records`, `Columns` and `Values` layouts carry no index, so the rows are labelled
+    /// `["0", "1", ...]`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let df : DataFrame<f64> = DataFrame::read_json(r#"{"b": [1.0, null], "a": [2.5, 3.0]}"#,
+    ///                                                JsonOrient::Columns).unwrap();
+    /// assert_eq!(df.columns, vec!["b", "a"]);
+    /// assert!(df.data[[1, 0]].is_nan());
+    ///
+    /// let ragged = DataFrame::<f64>::read_json(r#"{"a": [], "b": [1.0, 2.0]}"#, JsonOrient::Columns);
+    /// assert!(ragged.is_err());
+    /// ```
+    fn read_json(json: &str, orient: JsonOrient) -> Result<DataFrame<T>> {
+        let root: Value = match serde_json::from_str(json) {
+            Ok(j) => j,
+            Err(e) => return Err(ErrorKind::ParseError(e.to_string()).into()),
+        };
+        let (columns, index, values) = match orient {
+            JsonOrient::Records => {
+                let records = as_array(&root)?;
+                let mut objects = Vec::with_capacity(records.len());
+                let mut columns: Vec<String> = Vec::new();
+                for record in records {
+                    let record = match *record {
+                        Value::Object(ref o) => o,
+                        _ => return Err(ErrorKind::ParseError("expected a record object".into()).into()),
+                    };
+                    for k in record.keys() {
+                        if !columns.contains(k) {
+                            columns.push(k.clone());
+                        }
+                    }
+                    objects.push(record);
+                }
+                let mut values = Vec::with_capacity(records.len() * columns.len());
+                for record in objects {
+                    for c in columns.iter() {
+                        values.push(match record.get(c) {
+                            Some(v) => decode_value(v)?,
+                            None => T::empty(),
+                        });
+                    }
+                }
+                (columns, default_labels(records.len()), values)
+            }
+            JsonOrient::Columns => {
+                let object = match root {
+                    Value::Object(ref o) => o,
+                    _ => return Err(ErrorKind::ParseError("expected an object of columns".into()).into()),
+                };
+                let columns: Vec<String> = object.keys().cloned().collect();
+                let mut data = Vec::with_capacity(columns.len());
+                for c in columns.iter() {
+                    data.push(as_array(&object[c])?);
+                }
+                let nrows = data.first().map(|x| x.len()).unwrap_or(0);
+                if let Some(col) = data.iter().find(|col| col.len() != nrows) {
+                    return Err(ErrorKind::IndexShapeMismatch(nrows.to_string(),
+                                                             col.len().to_string())
+                        .into());
+                }
+                let mut values = Vec::with_capacity(nrows * columns.len());
+                for i in 0..nrows {
+                    for col in data.iter() {
+                        values.push(decode_value(&col[i])?);
+                    }
+                }
+                (columns, default_labels(nrows), values)
+            }
+            JsonOrient::Split => {
+                let field = |name: &str| -> Result<&Vec<Value>> {
+                    match root.get(name) {
+                        Some(v) => as_array(v),
+                        None => Err(ErrorKind::ParseError(format!("missing field '{}'", name)).into()),
+                    }
+                };
+                let columns: Vec<String> = field("columns")?.iter().map(label).collect();
+                let index: Vec<String> = field("index")?.iter().map(label).collect();
+                let values = rows_to_values(field("data")?, columns.len())?;
+                (columns, index, values)
+            }
+            JsonOrient::Values => {
+                let rows = as_array(&root)?;
+                let ncols = match rows.first() {
+                    Some(r) => as_array(r)?.len(),
+                    None => 0,
+                };
+                let values = rows_to_values(rows, ncols)?;
+                (default_labels(ncols), default_labels(rows.len()), values)
+            }
+        };
+
+        let nrows = if columns.is_empty() { index.len() } else { values.len() / columns.len() };
+        let matrix = Array::from_shape_vec((nrows, columns.len()), values).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])?.index(&index[..])
+    }
+}
+
+impl<T> WriteJSON<T> for DataFrame<T>
+    where T: UtahNum + Serialize
+{
+    /// Write the dataframe as a JSON string laid out according to `orient`. Values that
+    /// have no JSON form, such as infinite floats, are written as `null` like empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[2.0, std::f64::NAN], [3.0, 4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// assert_eq!(df.to_json(JsonOrient::Records),
+    ///            r#"[{"a":2.0,"b":null},{"a":3.0,"b":4.0}]"#);
+    /// let back : DataFrame<f64> = DataFrame::read_json(&df.to_json(JsonOrient::Split),
+    ///                                                  JsonOrient::Split).unwrap();
+    /// assert_eq!(back.columns, df.columns);
+    /// assert!(back.data[[0, 1]].is_nan());
+    /// ```
+    fn to_json(&self, orient: JsonOrient) -> String {
+        let row = |i: usize| -> Value { self.data.row(i).iter().map(value).collect() };
+        let nrows = self.index.len();
+        let root = match orient {
+            JsonOrient::Records => {
+                (0..nrows)
+                    .map(|i| {
+                        let record: Map<String, Value> = self.columns
+                            .iter()
+                            .zip(self.data.row(i).iter())
+                            .map(|(c, x)| (c.clone(), value(x)))
+                            .collect();
+                        Value::Object(record)
+                    })
+                    .collect()
+            }
+            JsonOrient::Columns => {
+                let columns: Map<String, Value> = self.columns
+                    .iter()
+                    .enumerate()
+                    .map(|(j, c)| (c.clone(), self.data.column(j).iter().map(value).collect()))
+                    .collect();
+                Value::Object(columns)
+            }
+            JsonOrient::Split => {
+                let mut split = Map::new();
+                split.insert("index".to_string(), self.index.iter().cloned().collect());
+                split.insert("columns".to_string(), self.columns.iter().cloned().collect());
+                split.insert("data".to_string(), (0..nrows).map(row).collect());
+                Value::Object(split)
+            }
+            JsonOrient::Values => (0..nrows).map(row).collect(),
+        };
+        root.to_string()
+    }
+}
+
+fn value<T: UtahNum + Serialize>(x: &T) -> Value {
+    if x.is_empty() {
+        Value::Null
+    } else {
+        serde_json::to_value(x).unwrap_or(Value::Null)
+    }
+}
+
+fn label(j: &Value) -> String {
+    match *j {
+        Value::String(ref s) => s.clone(),
+        ref other => other.to_string(),
+    }
+}
+
+fn default_labels(n: usize) -> Vec<String> {
+    (0..n).map(|x| x.to_string()).collect()
+}
+
+fn as_array(j: &Value) -> Result<&Vec<Value>> {
+    match *j {
+        Value::Array(ref a) => Ok(a),
+        _ => Err(ErrorKind::ParseError("expected an array".into()).into()),
+    }
+}
+
+fn decode_value<T: UtahNum + DeserializeOwned>(j: &Value) -> Result<T> {
+    match *j {
+        Value::Null => Ok(T::empty()),
+        _ => {
+            match T::deserialize(j) {
+                Ok(v) => Ok(v),
+                Err(e) => Err(ErrorKind::ParseError(e.to_string()).into()),
+            }
+        }
+    }
+}
+
+fn rows_to_values<T: UtahNum + DeserializeOwned>(rows: &[Value], ncols: usize) -> Result<Vec<T>> {
+    let mut values = Vec::with_capacity(rows.len() * ncols);
+    for r in rows {
+        let r = as_array(r)?;
+        if r.len() != ncols {
+            return Err(ErrorKind::ColumnShapeMismatch(ncols.to_string(), r.len().to_string())
+                .into());
+        }
+        for v in r {
+            values.push(decode_value(v)?);
+        }
+    }
+    Ok(values)
+}
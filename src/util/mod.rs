@@ -1,15 +1,43 @@
 //! Utah utilities
 pub use super::*;
+#[cfg(feature = "arrow")]
+pub use self::arrow_ipc::*;
+pub use self::cast::*;
+pub use self::categorical::*;
+pub use self::datetime::*;
 pub use self::error::*;
+#[cfg(feature = "serde-1")]
+pub use self::json::*;
 pub use self::macros::*;
+#[cfg(feature = "parquet")]
+pub use self::parquet_io::*;
 pub use self::readcsv::*;
+pub use self::scale::*;
+pub use self::schema::*;
+pub use self::strings::*;
+#[cfg(feature = "sqlite")]
+pub use self::sql::*;
 pub use self::traits::*;
 pub use self::types::*;
 
+#[cfg(feature = "arrow")]
+pub mod arrow_ipc;
+pub mod cast;
+pub mod categorical;
+pub mod datetime;
 #[macro_use]
 pub mod error;
 #[macro_use]
 pub mod macros;
+#[cfg(feature = "parquet")]
+pub mod parquet_io;
+#[cfg(feature = "serde-1")]
+pub mod json;
 pub mod readcsv;
+pub mod scale;
+pub mod schema;
+pub mod strings;
+#[cfg(feature = "sqlite")]
+pub mod sql;
 pub mod traits;
 pub mod types;
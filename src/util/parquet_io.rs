@@ -0,0 +1,143 @@
+//! Reading and writing dataframes as Parquet files.
+//!
+//! Frames are converted through Arrow record batches, so the index is stored in the
+//! `__index_level_0__` column and null slots map to empty cells.
+use super::*;
+use std::fs::File;
+use std::path::Path;
+use parquet::arrow::{ArrowWriter, ProjectionMask};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::basic::{Compression, GzipLevel, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use arrow::compute::concat_batches;
+use arrow::record_batch::RecordBatchReader;
+
+/// The compression codec used for the column chunks of a Parquet file.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+    Gzip,
+    Zstd,
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(c: ParquetCompression) -> Compression {
+        match c {
+            ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP(GzipLevel::default()),
+            ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+        }
+    }
+}
+
+pub trait ReadParquet<T>
+    where T: ArrowElement
+{
+    fn read_parquet<P: AsRef<Path>>(path: P, columns: Option<&[&str]>) -> Result<DataFrame<T>>;
+}
+
+pub trait WriteParquet<T>
+    where T: ArrowElement
+{
+    fn write_parquet<P: AsRef<Path>>(&self, path: P, compression: ParquetCompression) -> Result<()>;
+}
+
+impl<T> ReadParquet<T> for DataFrame<T>
+    where T: ArrowElement
+{
+    /// Read a Parquet file. When `columns` is given, only those columns (and the index) are
+    /// read from disk, and they are returned in the order given.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 2.0, 3.0], [4.0, std::f64::NAN, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+    /// let path = std::env::temp_dir().join("utah2_doc_example.parquet");
+    /// df.write_parquet(&path, ParquetCompression::Snappy).unwrap();
+    ///
+    /// let back : DataFrame<f64> = DataFrame::read_parquet(&path, Some(&["c", "b"])).unwrap();
+    /// assert_eq!(back.columns, vec!["c", "b"]);
+    /// assert_eq!(back.data[[0, 0]], 3.0);
+    /// assert!(back.data[[1, 1]].is_nan());
+    /// ```
+    fn read_parquet<P: AsRef<Path>>(path: P, columns: Option<&[&str]>) -> Result<DataFrame<T>> {
+        let mut builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+        if let Some(columns) = columns {
+            let schema = builder.schema().clone();
+            let mut roots = Vec::with_capacity(columns.len() + 1);
+            if let Ok(i) = schema.index_of(INDEX_COLUMN) {
+                roots.push(i);
+            }
+            for name in columns {
+                match schema.index_of(name) {
+                    Ok(i) => roots.push(i),
+                    Err(_) => return Err(ErrorKind::InvalidColumnName(name.to_string()).into()),
+                }
+            }
+            let mask = ProjectionMask::roots(builder.parquet_schema(), roots);
+            builder = builder.with_projection(mask);
+        }
+        let reader = builder.build()?;
+        let schema = reader.schema();
+        let batches = reader.collect::<::std::result::Result<Vec<_>, _>>()?;
+        let batch = concat_batches(&schema, &batches)?;
+        let df = DataFrame::from_record_batch(&batch)?;
+        // The projection keeps the file's column order.
+        match columns {
+            Some(columns) => df.reorder_columns(columns),
+            None => Ok(df),
+        }
+    }
+}
+
+impl<T> WriteParquet<T> for DataFrame<T>
+    where T: ArrowElement
+{
+    /// Write the dataframe to a Parquet file using the given compression codec.
+    fn write_parquet<P: AsRef<Path>>(&self, path: P, compression: ParquetCompression) -> Result<()> {
+        let batch = self.to_record_batch()?;
+        let props = WriterProperties::builder().set_compression(compression.into()).build();
+        let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn round_trip_every_codec() {
+        let a = arr2(&[[1, 0], [3, 4], [5, 6]]);
+        let df: DataFrame<i32> = DataFrame::new(a)
+            .index(&["x", "y", "z"])
+            .unwrap()
+            .columns(&["a", "b"])
+            .unwrap();
+        let codecs = [ParquetCompression::Uncompressed,
+                      ParquetCompression::Snappy,
+                      ParquetCompression::Gzip,
+                      ParquetCompression::Zstd];
+        for (i, codec) in codecs.iter().enumerate() {
+            let path = ::std::env::temp_dir().join(format!("utah2_round_trip_{}.parquet", i));
+            df.write_parquet(&path, *codec).unwrap();
+            let back: DataFrame<i32> = DataFrame::read_parquet(&path, None).unwrap();
+            assert_eq!(back, df);
+        }
+    }
+
+    #[test]
+    fn unknown_projected_column() {
+        let df: DataFrame<f64> = DataFrame::new(arr2(&[[1.0]]));
+        let path = ::std::env::temp_dir().join("utah2_unknown_column.parquet");
+        df.write_parquet(&path, ParquetCompression::Snappy).unwrap();
+        let res: Result<DataFrame<f64>> = DataFrame::read_parquet(&path, Some(&["missing"]));
+        assert!(res.is_err());
+    }
+}
@@ -7,41 +7,332 @@
 ///
 use super::*;
 use ndarray::Array;
-use rustc_serialize::Decodable;
-use std::fmt::Debug;
-use std::str::FromStr;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 use csv;
 
 
 
 pub trait ReadCSV<T>
-    where T: UtahNum + Decodable
+    where T: UtahNum + DeserializeOwned
 {
     fn read_csv(file: &'static str) -> Result<DataFrame<T>>;
+    fn read_tsv(file: &str) -> Result<DataFrame<T>>;
+    fn read_delimited(file: &str, delimiter: u8) -> Result<DataFrame<T>>;
+}
+
+/// A column of a fixed-width file: its name, the character offset it starts at, and its width.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedWidthColumn {
+    pub name: String,
+    pub start: usize,
+    pub width: usize,
+}
+
+impl FixedWidthColumn {
+    pub fn new(name: &str, start: usize, width: usize) -> FixedWidthColumn {
+        FixedWidthColumn {
+            name: name.to_string(),
+            start,
+            width,
+        }
+    }
+}
+
+pub trait ReadFWF<T>
+    where T: UtahNum + DeserializeOwned
+{
+    fn read_fwf(file: &str, specs: &[FixedWidthColumn], skip_rows: usize) -> Result<DataFrame<T>>;
+}
+
+pub trait InferCSV {
+    fn infer_csv_types(file: &str, sample_rows: usize) -> Result<Schema>;
+    fn read_csv_inferred(file: &str, sample_rows: usize) -> Result<DataFrame<InnerType>>;
+    fn read_csv_schema(file: &str, schema: &Schema) -> Result<DataFrame<InnerType>>;
 }
 
 impl<T> ReadCSV<T> for DataFrame<T>
-    where T: UtahNum + Decodable + FromStr + Debug
+    where T: UtahNum + DeserializeOwned
 {
+    /// Read a CSV file with a header row. Each record is deserialized into typed values, and
+    /// empty fields become empty cells.
     fn read_csv(file: &'static str) -> Result<DataFrame<T>> {
-        let mut rdr = csv::Reader::from_path(file).unwrap();
-        let columns : Vec<String> = rdr.headers().unwrap().iter().map(|t| t.to_string()).collect();
-        let (mut nrow, ncol) = (0, columns.len());
-        let mut v: Vec<T> = Vec::new();
+        DataFrame::read_delimited(file, b',')
+    }
+
+    /// Read a tab-separated file with a header row.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let path = std::env::temp_dir().join("utah2_doc_example.tsv");
+    /// std::fs::write(&path, "a\tb\n1\t2\n3\t\n").unwrap();
+    /// let df : DataFrame<f64> = DataFrame::read_tsv(path.to_str().unwrap()).unwrap();
+    /// assert_eq!(df.columns, vec!["a", "b"]);
+    /// assert_eq!(df.data[[1, 0]], 3.0);
+    /// assert!(df.data[[1, 1]].is_nan());
+    /// ```
+    fn read_tsv(file: &str) -> Result<DataFrame<T>> {
+        DataFrame::read_delimited(file, b'\t')
+    }
+
+    /// Read a delimited file with a header row, splitting fields on `delimiter`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let path = std::env::temp_dir().join("utah2_doc_example.ssv");
+    /// std::fs::write(&path, "a;b\n1;2\n3;4\n").unwrap();
+    /// let df : DataFrame<i32> = DataFrame::read_delimited(path.to_str().unwrap(), b';').unwrap();
+    /// assert_eq!(df.data, arr2(&[[1, 2], [3, 4]]));
+    ///
+    /// std::fs::write(&path, "a;b\n1;2\n3;x\n").unwrap();
+    /// let err = DataFrame::<i32>::read_delimited(path.to_str().unwrap(), b';').unwrap_err();
+    /// assert!(err.to_string().contains("line: 3"));
+    /// ```
+    fn read_delimited(file: &str, delimiter: u8) -> Result<DataFrame<T>> {
+        let mut rdr = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .from_path(file)
+            .map_err(csv_error)?;
+        let columns : Vec<String> = rdr.headers().map_err(csv_error)?.iter().map(|t| t.to_string()).collect();
+        let (nrow, v) = parse_records(rdr.records())?;
+        let matrix = Array::from_shape_vec((nrow, columns.len()), v).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])
+    }
+}
+
+impl<T> ReadFWF<T> for DataFrame<T>
+    where T: UtahNum + DeserializeOwned
+{
+    /// Read a fixed-width file, cutting each line into the fields described by `specs`.
+    ///
+    /// The first `skip_rows` lines and any blank lines are ignored. Fields are trimmed, and
+    /// fields that are blank or lie past the end of a line become empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let path = std::env::temp_dir().join("utah2_doc_example.fwf");
+    /// std::fs::write(&path, "id   value\n  1    2.5\n\n  2\n  3       \n").unwrap();
+    /// let specs = [FixedWidthColumn::new("id", 0, 3), FixedWidthColumn::new("value", 3, 8)];
+    /// let df : DataFrame<f64> = DataFrame::read_fwf(path.to_str().unwrap(), &specs, 1).unwrap();
+    /// assert_eq!(df.columns, vec!["id", "value"]);
+    /// assert_eq!(df.data.column(0).to_vec(), vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(df.data[[0, 1]], 2.5);
+    /// assert!(df.data[[1, 1]].is_nan());
+    /// assert!(df.data[[2, 1]].is_nan());
+    ///
+    /// std::fs::write(&path, "id   value\n  1    2.5\n\n  x    1.0\n").unwrap();
+    /// let err = DataFrame::<f64>::read_fwf(path.to_str().unwrap(), &specs, 1).unwrap_err();
+    /// assert!(err.to_string().contains("line: 4"));
+    /// ```
+    fn read_fwf(file: &str, specs: &[FixedWidthColumn], skip_rows: usize) -> Result<DataFrame<T>> {
+        let rdr = BufReader::new(File::open(file)?);
+        let mut records = Vec::new();
+        for (n, line) in rdr.lines().enumerate().skip(skip_rows) {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let chars: Vec<char> = line.chars().collect();
+            let mut record: csv::StringRecord = specs.iter()
+                .map(|s| {
+                    let start = s.start.min(chars.len());
+                    let end = (s.start + s.width).min(chars.len());
+                    chars[start..end].iter().collect::<String>().trim().to_string()
+                })
+                .collect();
+            let mut pos = csv::Position::new();
+            pos.set_line(n as u64 + 1).set_record(records.len() as u64);
+            record.set_position(Some(pos));
+            records.push(Ok(record));
+        }
+        let columns: Vec<String> = specs.iter().map(|s| s.name.clone()).collect();
+        let (nrow, v) = parse_records(records.into_iter())?;
+        let matrix = Array::from_shape_vec((nrow, columns.len()), v).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])
+    }
+}
+
+impl InferCSV for DataFrame<InnerType> {
+    /// Infer the type of each column of a CSV file from its first `sample_rows` records.
+    ///
+    /// A column is typed as the narrowest of bool, integer, float, date (`%Y-%m-%d`), timestamp
+    /// and string that fits every non-empty sampled value. Columns with no sampled values are
+    /// floats.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let path = std::env::temp_dir().join("utah2_doc_infer.csv");
+    /// std::fs::write(&path, "id,price,day,note\n1,2.5,2020-01-01,\n2,3,2020-01-02,x\n").unwrap();
+    /// let schema = DataFrame::infer_csv_types(path.to_str().unwrap(), 10).unwrap();
+    /// let dtypes: Vec<DType> = schema.fields.iter().map(|f| f.dtype).collect();
+    /// assert_eq!(dtypes, vec![DType::Int64, DType::Float, DType::Date, DType::Str]);
+    /// assert!(schema.field("note").unwrap().nullable);
+    /// assert!(!schema.field("id").unwrap().nullable);
+    /// ```
+    fn infer_csv_types(file: &str, sample_rows: usize) -> Result<Schema> {
+        let mut rdr = csv::Reader::from_path(file).map_err(csv_error)?;
+        let columns: Vec<String> = rdr.headers().map_err(csv_error)?.iter().map(|t| t.to_string()).collect();
+        let mut sample = Vec::new();
+        for record in rdr.records().take(sample_rows) {
+            sample.push(record.map_err(csv_error)?);
+        }
+        Ok(Schema::new(infer_fields(&columns[..], &sample[..])))
+    }
+
+    /// Read a CSV file into a mixed-type dataframe, typing each column from its first
+    /// `sample_rows` records. Records past the sample that don't fit their column's inferred
+    /// type are reported as a `ParseError` rather than a panic.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let path = std::env::temp_dir().join("utah2_doc_inferred.csv");
+    /// std::fs::write(&path, "id,name\n1,a\n2,b\n,c\n").unwrap();
+    /// let df = DataFrame::read_csv_inferred(path.to_str().unwrap(), 2).unwrap();
+    /// assert_eq!(df.data[[1, 0]], InnerType::Int64(2));
+    /// assert_eq!(df.data[[2, 0]], InnerType::Empty);
+    /// assert_eq!(df.data[[2, 1]], InnerType::from("c"));
+    ///
+    /// std::fs::write(&path, "id,name\n1,a\n2,b\nthree,c\n").unwrap();
+    /// let err = DataFrame::read_csv_inferred(path.to_str().unwrap(), 2).unwrap_err();
+    /// assert!(err.to_string().contains("line 4: field 'id': cannot parse 'three'"));
+    /// ```
+    fn read_csv_inferred(file: &str, sample_rows: usize) -> Result<DataFrame<InnerType>> {
+        let mut rdr = csv::Reader::from_path(file).map_err(csv_error)?;
+        let columns: Vec<String> = rdr.headers().map_err(csv_error)?.iter().map(|t| t.to_string()).collect();
+        let mut records = Vec::new();
         for record in rdr.records() {
-            nrow += 1;
-            let e: Vec<T> = record.unwrap().iter().map(|t| parse_str::<T>(t)).collect();
-            v.extend(e.into_iter())
+            records.push(record.map_err(csv_error)?);
         }
+        let n = sample_rows.min(records.len());
+        // Values past the sample may be empty even where the sample had none.
+        let fields: Vec<Field> = infer_fields(&columns[..], &records[..n])
+            .into_iter()
+            .map(|f| Field { nullable: true, ..f })
+            .collect();
+        parse_typed(&records[..], &fields[..])
+    }
 
-        let matrix = Array::from_shape_vec((nrow, ncol), v).unwrap();
-        DataFrame::new(matrix).columns(&columns[..])
+    /// Read a CSV file into a mixed-type dataframe with the column types given by `schema`.
+    ///
+    /// The header must name the schema's columns in order. A value that doesn't parse as its
+    /// column's type, or an empty value in a column that isn't nullable, is a `SchemaMismatch`.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// let schema = Schema::new(vec![Field::new("id", DType::Int32, false),
+    ///                               Field::new("when", DType::Date, true)]);
+    /// let path = std::env::temp_dir().join("utah2_doc_schema.csv");
+    /// std::fs::write(&path, "id,when\n1,2020-01-01\n2,\n").unwrap();
+    /// let df = DataFrame::read_csv_schema(path.to_str().unwrap(), &schema).unwrap();
+    /// assert_eq!(df.data[[0, 0]], InnerType::Int32(1));
+    /// assert_eq!(df.data[[1, 1]], InnerType::Empty);
+    ///
+    /// std::fs::write(&path, "id,when\n,2020-01-01\n").unwrap();
+    /// let err = DataFrame::read_csv_schema(path.to_str().unwrap(), &schema).unwrap_err();
+    /// assert_eq!(err.to_string(),
+    ///            "schema mismatch: line 2: field 'id': empty value in a non-nullable column");
+    ///
+    /// std::fs::write(&path, "when,id\n2020-01-01,1\n").unwrap();
+    /// let err = DataFrame::read_csv_schema(path.to_str().unwrap(), &schema).unwrap_err();
+    /// assert!(err.to_string().starts_with("schema mismatch: expected columns"));
+    /// ```
+    fn read_csv_schema(file: &str, schema: &Schema) -> Result<DataFrame<InnerType>> {
+        let mut rdr = csv::Reader::from_path(file).map_err(csv_error)?;
+        let columns: Vec<String> = rdr.headers().map_err(csv_error)?.iter().map(|t| t.to_string()).collect();
+        if columns != schema.names() {
+            return Err(ErrorKind::SchemaMismatch(format!("expected columns {:?}, found {:?}",
+                                                         schema.names(),
+                                                         columns))
+                .into());
+        }
+        let mut records = Vec::new();
+        for record in rdr.records() {
+            records.push(record.map_err(csv_error)?);
+        }
+        parse_typed(&records[..], &schema.fields[..]).map_err(|e| match *e.kind() {
+            ErrorKind::ParseError(ref t) => ErrorKind::SchemaMismatch(t.clone()).into(),
+            _ => e,
+        })
+    }
+}
+
+/// Parse each record into mixed-type cells, using the type of the matching field.
+fn parse_typed(records: &[csv::StringRecord], fields: &[Field]) -> Result<DataFrame<InnerType>> {
+    let mut v = Vec::with_capacity(records.len() * fields.len());
+    for record in records.iter() {
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+        for (text, field) in record.iter().zip(fields.iter()) {
+            match InnerType::parse(text, field.dtype) {
+                Some(InnerType::Empty) if !field.nullable => {
+                    return Err(ErrorKind::ParseError(format!("line {}: field '{}': empty value \
+                                                              in a non-nullable column",
+                                                             line,
+                                                             field.name))
+                        .into());
+                }
+                Some(x) => v.push(x),
+                None => {
+                    return Err(ErrorKind::ParseError(format!("line {}: field '{}': cannot \
+                                                              parse '{}' as {:?}",
+                                                             line,
+                                                             field.name,
+                                                             text,
+                                                             field.dtype))
+                        .into());
+                }
+            }
+        }
     }
+    let columns: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+    let matrix = Array::from_shape_vec((records.len(), columns.len()), v).unwrap();
+    DataFrame::new(matrix).columns(&columns[..])
 }
-fn parse_str<T: FromStr>(text: &str) -> T {
-    match text.parse::<T>(){
-        Ok(p) => p,
-        Err(_e) => panic!("Error in converting the data")
+
+/// Pick the narrowest type that fits every non-empty value of each column.
+fn infer_fields(columns: &[String], records: &[csv::StringRecord]) -> Vec<Field> {
+    let candidates = [DType::Bool, DType::Int64, DType::Float, DType::Date, DType::DateTime];
+    columns.iter()
+        .enumerate()
+        .map(|(j, name)| {
+            let values: Vec<&str> = records.iter()
+                .filter_map(|r| r.get(j))
+                .map(|t| t.trim())
+                .collect();
+            let present: Vec<&str> = values.iter().cloned().filter(|t| !t.is_empty()).collect();
+            let nullable = present.len() < records.len();
+            let dtype = if present.is_empty() {
+                DType::Float
+            } else {
+                candidates.iter()
+                    .cloned()
+                    .find(|d| present.iter().all(|t| InnerType::parse(t, *d).is_some()))
+                    .unwrap_or(DType::Str)
+            };
+            Field::new(name, dtype, nullable)
+        })
+        .collect()
+}
+
+/// Deserialize each record into typed values, mapping empty fields to empty cells.
+fn parse_records<T, I>(records: I) -> Result<(usize, Vec<T>)>
+    where T: UtahNum + DeserializeOwned,
+          I: Iterator<Item = csv::Result<csv::StringRecord>>
+{
+    let mut nrow = 0;
+    let mut v: Vec<T> = Vec::new();
+    for record in records {
+        nrow += 1;
+        let record = record.map_err(csv_error)?;
+        let e: Vec<Option<T>> = record.deserialize(None).map_err(csv_error)?;
+        v.extend(e.into_iter().map(|t| t.unwrap_or_else(T::empty)))
     }
+    Ok((nrow, v))
+}
 
+fn csv_error(e: csv::Error) -> Error {
+    ErrorKind::ParseError(e.to_string()).into()
 }
@@ -0,0 +1,143 @@
+//! Fit/transform feature scaling of the columns of a `DataFrame<f64>`.
+use super::*;
+use ndarray::Array;
+
+/// A fitted scaling, mapping each value `x` of a column to `(x - center) / scale`.
+///
+/// The parameters are kept as a dataframe with the rows `center` and `scale` and one column per
+/// fitted column, so they can be inspected, stored and reused on new data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scaler {
+    pub method: ScaleMethod,
+    pub params: DataFrame<f64>,
+}
+
+impl Scaler {
+    /// Fit the parameters of `method` to each column of `df`, ignoring empty values. Columns
+    /// with a scale of zero are only centered.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0, 10.0], [2.0, 20.0], [3.0, std::f64::NAN]]);
+    /// let mut df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let scaler = Scaler::fit(&df, ScaleMethod::MinMax).unwrap();
+    /// assert_eq!(scaler.params.index, vec!["center", "scale"]);
+    /// assert_eq!(scaler.params.data, arr2(&[[1.0, 10.0], [2.0, 10.0]]));
+    /// scaler.transform(&mut df).unwrap();
+    /// assert_eq!(df.data.column(0).to_vec(), vec![0.0, 0.5, 1.0]);
+    /// assert!(df.data[[2, 1]].is_nan());
+    /// ```
+    pub fn fit(df: &DataFrame<f64>, method: ScaleMethod) -> Result<Scaler> {
+        let ncols = df.columns.len();
+        let (center, scale): (Vec<f64>, Vec<f64>) = match method {
+            ScaleMethod::MinMax => {
+                (0..ncols)
+                    .map(|j| {
+                        let values = present(df, j);
+                        if values.is_empty() {
+                            return (f64::empty(), f64::empty());
+                        }
+                        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+                        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                        (min, max - min)
+                    })
+                    .unzip()
+            }
+            ScaleMethod::Standard => {
+                (0..ncols)
+                    .map(|j| {
+                        let values = present(df, j);
+                        let n = values.len() as f64;
+                        let mean = values.iter().sum::<f64>() / n;
+                        let var = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+                        (mean, var.sqrt())
+                    })
+                    .unzip()
+            }
+            ScaleMethod::Robust => {
+                (0..ncols)
+                    .map(|j| {
+                        let mut values = present(df, j);
+                        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                        (quantile(&values[..], 0.5),
+                         quantile(&values[..], 0.75) - quantile(&values[..], 0.25))
+                    })
+                    .unzip()
+            }
+        };
+        let mut data = center;
+        data.extend(scale);
+        Ok(Scaler {
+            method,
+            params: DataFrame {
+                columns: df.columns.clone(),
+                data: Array::from_shape_vec((2, ncols), data).unwrap(),
+                index: vec!["center".to_string(), "scale".to_string()],
+            },
+        })
+    }
+
+    /// Fit the parameters to `df`, then scale it in place.
+    pub fn fit_transform(df: &mut DataFrame<f64>, method: ScaleMethod) -> Result<Scaler> {
+        let scaler = Scaler::fit(df, method)?;
+        scaler.transform(df)?;
+        Ok(scaler)
+    }
+
+    /// Scale the columns of `df` in place, matching them to the fitted columns by name.
+    pub fn transform(&self, df: &mut DataFrame<f64>) -> Result<()> {
+        let params = self.lookup(df)?;
+        for ((_, mut column), (center, scale)) in df.df_iter_mut(UtahAxis::Column).zip(params) {
+            column.mapv_inplace(|x| (x - center) / scale);
+        }
+        Ok(())
+    }
+
+    /// Undo the scaling of the columns of `df` in place.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[1.0], [2.0], [3.0], [10.0]]);
+    /// let original : DataFrame<f64> = DataFrame::new(a).columns(&["a"]).unwrap();
+    /// let mut df = original.clone();
+    /// let scaler = Scaler::fit_transform(&mut df, ScaleMethod::Robust).unwrap();
+    /// assert_eq!(scaler.params.data, arr2(&[[2.5], [3.0]]));
+    /// scaler.inverse_transform(&mut df).unwrap();
+    /// assert_eq!(df, original);
+    /// ```
+    pub fn inverse_transform(&self, df: &mut DataFrame<f64>) -> Result<()> {
+        let params = self.lookup(df)?;
+        for ((_, mut column), (center, scale)) in df.df_iter_mut(UtahAxis::Column).zip(params) {
+            column.mapv_inplace(|x| x * scale + center);
+        }
+        Ok(())
+    }
+
+    /// The center and scale for each column of `df`, with zero scales replaced by one.
+    fn lookup(&self, df: &DataFrame<f64>) -> Result<Vec<(f64, f64)>> {
+        df.columns
+            .iter()
+            .map(|c| {
+                let j = self.params.column_position(c)?;
+                let scale = self.params.data[[1, j]];
+                Ok((self.params.data[[0, j]], if scale == 0.0 { 1.0 } else { scale }))
+            })
+            .collect()
+    }
+}
+
+fn present(df: &DataFrame<f64>, j: usize) -> Vec<f64> {
+    df.data.column(j).iter().cloned().filter(|x| !x.is_empty()).collect()
+}
+
+/// The `q`-th quantile of sorted values, interpolating linearly between neighbours.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::empty();
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
@@ -0,0 +1,180 @@
+//! Column schemas: the ordered names, types and nullability of a dataframe's columns.
+use super::*;
+use std::fmt;
+#[cfg(feature = "serde-1")]
+use serde_derive::{Deserialize, Serialize};
+
+/// An element whose type can be reported in a schema.
+pub trait Typed {
+    /// The type of the value, or `None` for an empty cell.
+    fn dtype(&self) -> Option<DType>;
+    /// The type reported for a column holding no values.
+    fn default_dtype() -> DType;
+}
+
+impl Typed for f64 {
+    fn dtype(&self) -> Option<DType> {
+        if self.is_nan() { None } else { Some(DType::Float) }
+    }
+    fn default_dtype() -> DType {
+        DType::Float
+    }
+}
+
+impl Typed for i32 {
+    fn dtype(&self) -> Option<DType> {
+        Some(DType::Int32)
+    }
+    fn default_dtype() -> DType {
+        DType::Int32
+    }
+}
+
+impl Typed for i64 {
+    fn dtype(&self) -> Option<DType> {
+        Some(DType::Int64)
+    }
+    fn default_dtype() -> DType {
+        DType::Int64
+    }
+}
+
+impl Typed for InnerType {
+    fn dtype(&self) -> Option<DType> {
+        InnerType::dtype(self)
+    }
+    fn default_dtype() -> DType {
+        DType::Float
+    }
+}
+
+/// An ordered list of column fields.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+pub trait HasSchema {
+    fn schema(&self) -> Schema;
+}
+
+impl Schema {
+    pub fn new(fields: Vec<Field>) -> Schema {
+        Schema { fields }
+    }
+
+    /// The column names, in order.
+    pub fn names(&self) -> Vec<String> {
+        self.fields.iter().map(|f| f.name.clone()).collect()
+    }
+
+    /// Look up a field by column name.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Check that `other` has the same columns in the same order, that each column's type can
+    /// be held by this schema's type, and that only nullable columns contain empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let expected = Schema::new(vec![Field::new("a", DType::Float, false),
+    ///                                 Field::new("b", DType::Float, true)]);
+    /// let a = arr2(&[[2.0, std::f64::NAN], [3.0, 4.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// assert!(expected.validate(&df.schema()).is_ok());
+    /// let df : DataFrame<f64> = DataFrame::new(arr2(&[[std::f64::NAN, 1.0]])).columns(&["a", "b"]).unwrap();
+    /// let err = expected.validate(&df.schema()).unwrap_err();
+    /// assert_eq!(err.to_string(), "schema mismatch: column 'a' has empty cells but is not nullable");
+    /// ```
+    pub fn validate(&self, other: &Schema) -> Result<()> {
+        if self.fields.len() != other.fields.len() {
+            return Err(ErrorKind::ColumnShapeMismatch(self.fields.len().to_string(),
+                                                      other.fields.len().to_string())
+                .into());
+        }
+        for (expected, actual) in self.fields.iter().zip(other.fields.iter()) {
+            if expected.name != actual.name {
+                return Err(ErrorKind::SchemaMismatch(format!("expected column '{}', found '{}'",
+                                                             expected.name,
+                                                             actual.name))
+                    .into());
+            }
+            if !widens_to(actual.dtype, expected.dtype) {
+                return Err(ErrorKind::SchemaMismatch(format!("column '{}' is {:?}, expected {:?}",
+                                                             actual.name,
+                                                             actual.dtype,
+                                                             expected.dtype))
+                    .into());
+            }
+            if actual.nullable && !expected.nullable {
+                return Err(ErrorKind::SchemaMismatch(format!("column '{}' has empty cells but \
+                                                              is not nullable",
+                                                             actual.name))
+                    .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for field in self.fields.iter() {
+            writeln!(f,
+                     "{}: {:?}{}",
+                     field.name,
+                     field.dtype,
+                     if field.nullable { " (nullable)" } else { "" })?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether values of type `from` can be held in a column of type `to` without loss.
+pub fn widens_to(from: DType, to: DType) -> bool {
+    match (from, to) {
+        (a, b) if a == b => true,
+        (DType::Int32, DType::Int64) | (DType::Int32, DType::Float) | (DType::Int64, DType::Float) => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The narrowest type that can hold values of both types.
+fn unify(a: DType, b: DType) -> DType {
+    if widens_to(a, b) {
+        b
+    } else if widens_to(b, a) {
+        a
+    } else {
+        DType::Str
+    }
+}
+
+impl<T> HasSchema for DataFrame<T>
+    where T: UtahNum + Typed
+{
+    /// Describe the columns of the dataframe. Each column's type is the narrowest type that
+    /// holds all of its values; columns mixing incompatible types are reported as strings.
+    fn schema(&self) -> Schema {
+        let fields = self.columns
+            .iter()
+            .enumerate()
+            .map(|(j, name)| {
+                let column = self.data.column(j);
+                let nullable = column.iter().any(|x| x.is_empty());
+                let dtype = column.iter()
+                    .filter(|x| !x.is_empty())
+                    .filter_map(|x| x.dtype())
+                    .fold(None, |acc: Option<DType>, d| Some(acc.map_or(d, |a| unify(a, d))))
+                    .unwrap_or_else(T::default_dtype);
+                Field::new(name, dtype, nullable)
+            })
+            .collect();
+        Schema::new(fields)
+    }
+}
@@ -0,0 +1,139 @@
+//! Reading query results into dataframes and writing dataframes to SQLite tables.
+//!
+//! `NULL` is read as an empty cell, and empty cells are written as `NULL`. The index is not
+//! stored, so frames read back from a table are labelled `["0", "1", ...]`.
+use super::*;
+use ndarray::Array;
+use rusqlite::{params_from_iter, Connection, OptionalExtension};
+use rusqlite::types::{FromSql, ToSql};
+
+/// An element type that can be stored in a SQLite column.
+pub trait SqlElement: UtahNum + FromSql + ToSql {
+    fn sql_type() -> &'static str;
+}
+
+impl SqlElement for f64 {
+    fn sql_type() -> &'static str {
+        "REAL"
+    }
+}
+
+impl SqlElement for i32 {
+    fn sql_type() -> &'static str {
+        "INTEGER"
+    }
+}
+
+/// What to do when writing to a table that already exists.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub enum IfExists {
+    Fail,
+    Replace,
+    Append,
+}
+
+pub trait ReadSQL<T>
+    where T: SqlElement
+{
+    fn from_sql(conn: &Connection, query: &str) -> Result<DataFrame<T>>;
+}
+
+pub trait WriteSQL<T>
+    where T: SqlElement
+{
+    fn to_sql(&self, conn: &Connection, table: &str, if_exists: IfExists) -> Result<()>;
+}
+
+impl<T> ReadSQL<T> for DataFrame<T>
+    where T: SqlElement
+{
+    /// Run a query and collect its result set into a dataframe, taking the column names from
+    /// the result set.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use rusqlite::Connection;
+    /// let conn = Connection::open_in_memory().unwrap();
+    /// conn.execute_batch("CREATE TABLE t (a REAL, b REAL);
+    ///                     INSERT INTO t VALUES (1.5, NULL), (2.0, 3.0);").unwrap();
+    /// let df : DataFrame<f64> = DataFrame::from_sql(&conn, "SELECT b, a FROM t").unwrap();
+    /// assert_eq!(df.columns, vec!["b", "a"]);
+    /// assert!(df.data[[0, 0]].is_nan());
+    /// ```
+    fn from_sql(conn: &Connection, query: &str) -> Result<DataFrame<T>> {
+        let mut stmt = conn.prepare(query)?;
+        let columns: Vec<String> = stmt.column_names().iter().map(|x| x.to_string()).collect();
+        let ncols = columns.len();
+        let mut rows = stmt.query([])?;
+        let (mut nrow, mut v) = (0, Vec::new());
+        while let Some(row) = rows.next()? {
+            nrow += 1;
+            for j in 0..ncols {
+                let x: Option<T> = row.get(j)?;
+                v.push(x.unwrap_or_else(T::empty));
+            }
+        }
+        let matrix = Array::from_shape_vec((nrow, ncols), v).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])
+    }
+}
+
+impl<T> WriteSQL<T> for DataFrame<T>
+    where T: SqlElement
+{
+    /// Write the dataframe to `table`, creating the table if needed.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// use rusqlite::Connection;
+    /// let conn = Connection::open_in_memory().unwrap();
+    /// let df : DataFrame<i32> = DataFrame::new(arr2(&[[1, 2], [3, 4]])).columns(&["a", "b"]).unwrap();
+    /// df.to_sql(&conn, "t", IfExists::Fail).unwrap();
+    /// df.to_sql(&conn, "t", IfExists::Append).unwrap();
+    /// assert!(df.to_sql(&conn, "t", IfExists::Fail).is_err());
+    /// let back : DataFrame<i32> = DataFrame::from_sql(&conn, "SELECT * FROM t").unwrap();
+    /// assert_eq!(back.data.dim(), (4, 2));
+    /// ```
+    fn to_sql(&self, conn: &Connection, table: &str, if_exists: IfExists) -> Result<()> {
+        let exists = conn.query_row("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                       [table],
+                       |_| Ok(()))
+            .optional()?
+            .is_some();
+        let name = quote(table);
+        let tx = conn.unchecked_transaction()?;
+        match (exists, if_exists) {
+            (true, IfExists::Fail) => return Err(ErrorKind::TableExists(table.to_string()).into()),
+            (true, IfExists::Replace) => {
+                tx.execute(&format!("DROP TABLE {}", name), [])?;
+            }
+            _ => {}
+        }
+        if !exists || if_exists == IfExists::Replace {
+            let defs: Vec<String> = self.columns
+                .iter()
+                .map(|c| format!("{} {}", quote(c), T::sql_type()))
+                .collect();
+            tx.execute(&format!("CREATE TABLE {} ({})", name, defs.join(", ")), [])?;
+        }
+        {
+            let columns: Vec<String> = self.columns.iter().map(|c| quote(c)).collect();
+            let params: Vec<String> = (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+            let mut stmt = tx.prepare(&format!("INSERT INTO {} ({}) VALUES ({})",
+                                               name,
+                                               columns.join(", "),
+                                               params.join(", ")))?;
+            for row in self.data.outer_iter() {
+                let values = row.iter().map(|x| if x.is_empty() { None } else { Some(x) });
+                stmt.execute(params_from_iter(values))?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
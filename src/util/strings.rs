@@ -0,0 +1,147 @@
+//! Vectorized string operations on the columns of mixed-type dataframes.
+//!
+//! Only `InnerType::Str` cells take part: any other cell gives an empty cell, or `false` in a
+//! mask.
+use super::*;
+use ndarray::Array;
+use regex::Regex;
+
+/// A string view of one column of a mixed-type dataframe.
+#[derive(Clone, Debug)]
+pub struct StringColumn<'a> {
+    pub df: &'a DataFrame<InnerType>,
+    pub column: usize,
+}
+
+impl<'a> StringColumn<'a> {
+    fn mask<F>(&self, f: F) -> Column<bool>
+        where F: Fn(&str) -> bool
+    {
+        self.df.data.column(self.column).map(|x| match *x {
+            InnerType::Str(ref s) => f(s),
+            _ => false,
+        })
+    }
+
+    fn map<F>(&self, f: F) -> Column<InnerType>
+        where F: Fn(&str) -> InnerType
+    {
+        self.df.data.column(self.column).map(|x| match *x {
+            InnerType::Str(ref s) => f(s),
+            _ => InnerType::Empty,
+        })
+    }
+
+    /// Whether each string contains `pattern`.
+    pub fn contains(&self, pattern: &str) -> Column<bool> {
+        self.mask(|s| s.contains(pattern))
+    }
+
+    /// Whether each string starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> Column<bool> {
+        self.mask(|s| s.starts_with(prefix))
+    }
+
+    /// Whether each string ends with `suffix`.
+    pub fn ends_with(&self, suffix: &str) -> Column<bool> {
+        self.mask(|s| s.ends_with(suffix))
+    }
+
+    /// Whether the regular expression `pattern` matches somewhere in each string.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[InnerType::from("a-12")], [InnerType::from("b")], [InnerType::Empty]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["code"]).unwrap();
+    /// let mask = df.str("code").unwrap().matches(r"\d+").unwrap();
+    /// assert_eq!(mask, arr1(&[true, false, false]));
+    /// assert!(df.str("code").unwrap().matches("(").is_err());
+    /// ```
+    pub fn matches(&self, pattern: &str) -> Result<Column<bool>> {
+        let re = Regex::new(pattern)?;
+        Ok(self.mask(|s| re.is_match(s)))
+    }
+
+    /// The text captured by `group` of the first match of `pattern` in each string, where
+    /// group 0 is the whole match. Strings without a match give empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("order 66")], [InnerType::from("none")]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["text"]).unwrap();
+    /// let digits = df.str("text").unwrap().extract(r"order (\d+)", 1).unwrap();
+    /// assert_eq!(digits[0], InnerType::from("66"));
+    /// assert_eq!(digits[1], InnerType::Empty);
+    /// ```
+    pub fn extract(&self, pattern: &str, group: usize) -> Result<Column<InnerType>> {
+        let re = Regex::new(pattern)?;
+        Ok(self.map(|s| {
+            re.captures(s)
+                .and_then(|c| c.get(group))
+                .map(|m| InnerType::from(m.as_str()))
+                .unwrap_or(InnerType::Empty)
+        }))
+    }
+
+    /// Replace every match of `pattern` in each string with `replacement`, which may refer to
+    /// capture groups as `$1` or `$name`.
+    pub fn replace(&self, pattern: &str, replacement: &str) -> Result<Column<InnerType>> {
+        let re = Regex::new(pattern)?;
+        Ok(self.map(|s| InnerType::from(re.replace_all(s, replacement).into_owned())))
+    }
+
+    pub fn to_lowercase(&self) -> Column<InnerType> {
+        self.map(|s| InnerType::from(s.to_lowercase()))
+    }
+
+    pub fn to_uppercase(&self) -> Column<InnerType> {
+        self.map(|s| InnerType::from(s.to_uppercase()))
+    }
+
+    /// Remove leading and trailing whitespace.
+    pub fn strip(&self) -> Column<InnerType> {
+        self.map(|s| InnerType::from(s.trim()))
+    }
+
+    /// The number of characters in each string, as `Int32` cells.
+    pub fn len(&self) -> Column<InnerType> {
+        self.map(|s| InnerType::Int32(s.chars().count() as i32))
+    }
+
+    /// Split each string on `separator` into the columns `<column>_0`, `<column>_1`, ...,
+    /// as many as the string with the most parts needs. Missing parts are empty cells.
+    ///
+    /// ```
+    /// use utah2::prelude::*;
+    /// use ndarray::arr2;
+    /// let a = arr2(&[[InnerType::from("x,y")], [InnerType::from("z")]]);
+    /// let df : DataFrame<InnerType> = DataFrame::new(a).columns(&["pair"]).unwrap();
+    /// let parts = df.str("pair").unwrap().split(",");
+    /// assert_eq!(parts.columns, vec!["pair_0", "pair_1"]);
+    /// assert_eq!(parts.data[[1, 1]], InnerType::Empty);
+    /// ```
+    pub fn split(&self, separator: &str) -> DataFrame<InnerType> {
+        let column = self.df.data.column(self.column);
+        let parts: Vec<Vec<&str>> = column.iter()
+            .map(|x| match *x {
+                InnerType::Str(ref s) => s.split(separator).collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+        let width = parts.iter().map(|p| p.len()).max().unwrap_or(0);
+        let mut values = Vec::with_capacity(parts.len() * width);
+        for p in parts.iter() {
+            values.extend((0..width).map(|k| {
+                p.get(k).map(|&s| InnerType::from(s)).unwrap_or_default()
+            }));
+        }
+        let name = &self.df.columns[self.column];
+        DataFrame {
+            columns: (0..width).map(|k| format!("{}_{}", name, k)).collect(),
+            data: Array::from_shape_vec((parts.len(), width), values).unwrap(),
+            index: self.df.index.clone(),
+        }
+    }
+}
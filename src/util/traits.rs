@@ -1,10 +1,13 @@
 
 use super::*;
 use std::iter::Iterator;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::ops::{Add, Sub, Mul, Div};
 use num::traits::{One, Zero};
 use ndarray::ArrayView1;
+use std::ops::Range;
+use std::collections::HashMap;
+use chrono::{NaiveDateTime, TimeDelta};
 
 pub trait UtahNum
     : Add<Output = Self> +
@@ -49,11 +52,18 @@ pub trait Constructor<'a, T>
     fn from_array<U: Clone>(data: Row<U>, axis: UtahAxis) -> DataFrame<T> where T: From<U>;
     fn index<U: Clone>(self, index: &'a [U]) -> Result<Self> where String: From<U>;
     fn columns<U: Clone>(self, columns: &'a [U]) -> Result<Self> where String: From<U>;
+    fn verify_integrity(self) -> Result<Self>;
     fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T>;
     fn df_iter_mut(&'a mut self, axis: UtahAxis) -> DataFrameMutIterator<'a, T>;
 }
 
 
+/// A selection of labels that resolves to a contiguous range of positions.
+pub trait LabelSlice {
+    fn positions(&self, labels: &[String]) -> Result<Range<usize>>;
+}
+
+
 pub trait Operations<'a, T>
     where T: 'a + UtahNum
 {
@@ -68,6 +78,13 @@ pub trait Operations<'a, T>
                          axis: UtahAxis)
                          -> AppendIter<'a, T>
         where String: From<&'a U>;
+    fn iloc(&'a self, rows: Range<usize>, columns: Range<usize>) -> Result<DataFrameView<'a, T>>;
+    fn loc<R, C>(&'a self, rows: R, columns: C) -> Result<DataFrameView<'a, T>>
+        where R: LabelSlice,
+              C: LabelSlice;
+    fn head(&'a self, n: usize) -> DataFrameView<'a, T>;
+    fn tail(&'a self, n: usize) -> DataFrameView<'a, T>;
+    fn sample(&'a self, size: SampleSize, replace: bool, seed: Option<u64>) -> Result<DataFrame<T>>;
     fn inner_left_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T>;
     fn outer_left_join(&'a self, other: &'a DataFrame<T>) -> OuterJoinIter<'a, T>;
     fn inner_right_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T>;
@@ -81,6 +98,111 @@ pub trait Operations<'a, T>
         where F: Fn(T) -> T,
               for<'r> F: Fn(T) -> T;
     fn impute(&'a mut self, strategy: ImputeStrategy, axis: UtahAxis) -> ImputeIter<'a, T>;
+    fn value_counts(&'a self,
+                    column: &str,
+                    normalize: bool,
+                    sort: bool,
+                    dropna: bool)
+                    -> Result<DataFrame<f64>>
+        where T: Display;
+    fn unique(&'a self, column: &str) -> Result<Vec<T>>;
+    fn nunique(&'a self, axis: UtahAxis) -> NUniqueIter<'a, T>;
+    fn filter(&'a self, mask: &Column<bool>) -> Result<DataFrame<T>>;
+    fn with_column(&'a self, name: &str, values: Column<T>) -> Result<DataFrame<T>>;
+    fn duplicated(&'a self, subset: Option<&[&str]>, keep: Keep) -> Result<Column<bool>>;
+    fn drop_duplicates(&'a self, subset: Option<&[&str]>, keep: Keep) -> Result<DataFrame<T>>;
+    fn duplicated_index(&'a self, keep: Keep) -> Column<bool>;
+    fn set_index(&'a self, column: &str) -> Result<DataFrame<T>> where T: Display;
+    fn reset_index(&'a self, name: &str) -> Result<DataFrame<T>> where T: CastFrom<InnerType>;
+    fn rename(&'a self, mapping: &HashMap<&str, &str>, axis: UtahAxis) -> DataFrame<T>;
+    fn reindex(&'a self, labels: &[&str], fill: Option<T>) -> Result<DataFrame<T>>;
+    fn reorder_columns(&'a self, columns: &[&str]) -> Result<DataFrame<T>>;
+    fn broadcast<F>(&'a self, values: &Row<T>, axis: UtahAxis, f: F) -> Result<DataFrame<T>>
+        where F: Fn(T, T) -> T;
+    fn group_by(&'a self, by: &Categorical) -> Result<Vec<(String, DataFrame<T>)>>;
+    fn join_on(&'a self,
+               other: &DataFrame<T>,
+               left_on: &Categorical,
+               right_on: &Categorical)
+               -> Result<DataFrame<T>>;
+}
+
+pub trait Split<'a, T>
+    where T: 'a + UtahNum
+{
+    fn train_test_split(&'a self,
+                        test_frac: f64,
+                        seed: Option<u64>,
+                        stratify: Option<&str>)
+                        -> Result<(DataFrame<T>, DataFrame<T>)>;
+    fn kfold(&'a self, k: usize, seed: Option<u64>) -> Result<KFold<'a, T>>;
+}
+
+pub trait Stats<'a, T>
+    where T: 'a + UtahNum
+{
+    fn corr(&'a self, method: CorrMethod) -> DataFrame<f64>;
+    fn cov(&'a self, ddof: usize) -> DataFrame<f64>;
+}
+
+pub trait TimeSeries<'a, T>
+    where T: 'a + UtahNum
+{
+    fn resample(&'a self, freq: &str) -> Result<Resample<'a, T>>;
+    fn asfreq(&'a self, freq: &str) -> Result<DataFrame<T>>;
+    fn shift(&'a self, periods: isize) -> DataFrame<T>;
+    fn datetime_index(&'a self) -> Result<Vec<NaiveDateTime>>;
+}
+
+pub trait DateTimeColumns {
+    fn parse_datetime_column(&self, column: &str, format: &str) -> Result<DataFrame<InnerType>>;
+    fn datetime_component(&self,
+                          column: &str,
+                          component: DateComponent)
+                          -> Result<Column<InnerType>>;
+    fn add_duration(&self, column: &str, duration: TimeDelta) -> Result<DataFrame<InnerType>>;
+    fn set_datetime_index(&self, column: &str) -> Result<DataFrame<InnerType>>;
+}
+
+pub trait Cast<T>
+    where T: UtahNum
+{
+    fn cast<U>(&self) -> DataFrame<U> where U: UtahNum + CastFrom<T>;
+    fn try_cast<U>(&self) -> Result<DataFrame<U>> where U: UtahNum + CastFrom<T>;
+}
+
+pub trait CastColumns {
+    fn cast_column(&self, column: &str, dtype: DType) -> Result<DataFrame<InnerType>>;
+    fn try_cast_column(&self, column: &str, dtype: DType) -> Result<DataFrame<InnerType>>;
+    fn cast_schema(&self, schema: &Schema) -> Result<DataFrame<InnerType>>;
+}
+
+pub trait StringColumns<'a> {
+    fn str(&'a self, column: &str) -> Result<StringColumn<'a>>;
+}
+
+pub trait ToCategorical {
+    fn to_categorical(&self, column: &str) -> Result<Categorical>;
+}
+
+pub trait Dummies {
+    fn get_dummies(&self,
+                   columns: &[&str],
+                   prefix: Option<&[&str]>,
+                   drop_first: bool)
+                   -> Result<DataFrame<InnerType>>;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_dummies(&self,
+                    prefix: &str,
+                    column: &str,
+                    default: Option<&str>)
+                    -> Result<DataFrame<InnerType>>;
+}
+
+pub trait Render<'a, T>
+    where T: 'a + UtahNum
+{
+    fn display(&'a self, options: DisplayOptions) -> DataFrameDisplay<'a, T> where T: Display;
 }
 
 pub trait Aggregate<'a, T>
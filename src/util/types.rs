@@ -1,19 +1,124 @@
 use super::*;
 use ndarray::{Array2, Array1, ArrayView1, ArrayView2, ArrayViewMut1};
 use std::iter::Chain;
+#[cfg(feature = "serde-1")]
+use serde_derive::{Deserialize, Serialize};
 
 
 #[derive( Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
 pub enum UtahAxis {
     Row,
     Column,
 }
 
 #[derive( Clone, Debug)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
 pub enum ImputeStrategy {
     Mean,
 }
 
+/// The layout of a dataframe serialized as JSON.
+///
+/// * `Records`: `[{column: value, ...}, ...]`
+/// * `Columns`: `{column: [values], ...}`
+/// * `Split`: `{"index": [labels], "columns": [labels], "data": [[values], ...]}`
+/// * `Values`: `[[values], ...]`
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum JsonOrient {
+    Records,
+    Columns,
+    Split,
+    Values,
+}
+
+/// The number of rows to draw when sampling a dataframe.
+#[derive( Clone, Debug, Copy)]
+pub enum SampleSize {
+    Count(usize),
+    Frac(f64),
+}
+
+/// A calendar or clock field of a timestamp.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum DateComponent {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Days since Monday, from 0 to 6.
+    Weekday,
+    /// Day of the year, from 1 to 366.
+    DayOfYear,
+}
+
+/// The correlation coefficient computed by `corr`.
+///
+/// * `Pearson`: linear correlation.
+/// * `Spearman`: Pearson correlation of the ranks, with ties given their average rank.
+/// * `Kendall`: Kendall's tau-b rank correlation, which accounts for ties.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum CorrMethod {
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+/// How a `Scaler` centers and scales each column.
+///
+/// * `Standard`: subtract the mean and divide by the population standard deviation.
+/// * `MinMax`: subtract the minimum and divide by the range, mapping onto `[0, 1]`.
+/// * `Robust`: subtract the median and divide by the interquartile range.
+#[derive( Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde-1", derive(Serialize, Deserialize))]
+pub enum ScaleMethod {
+    Standard,
+    MinMax,
+    Robust,
+}
+
+/// Which occurrence of a repeated row or label is not marked as a duplicate.
+///
+/// * `First`: every occurrence but the first is a duplicate.
+/// * `Last`: every occurrence but the last is a duplicate.
+/// * `None`: every occurrence is a duplicate.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum Keep {
+    First,
+    Last,
+    None,
+}
+
+/// Options controlling how a dataframe is rendered by `Display`.
+#[derive( Clone, Debug)]
+pub struct DisplayOptions {
+    pub max_rows: usize,
+    pub max_cols: usize,
+    pub precision: Option<usize>,
+    pub na_rep: String,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> DisplayOptions {
+        DisplayOptions {
+            max_rows: 20,
+            max_cols: 10,
+            precision: None,
+            na_rep: "NaN".to_string(),
+        }
+    }
+}
+
+/// A dataframe paired with the options used to render it.
+pub struct DataFrameDisplay<'a, T: 'a>
+    where T: UtahNum
+{
+    pub df: &'a DataFrame<T>,
+    pub options: DisplayOptions,
+}
+
 
 pub type Column<T> = Array1<T>;
 pub type Row<T> = Array1<T>;
@@ -38,6 +143,7 @@ pub type MaxIter<'a, T> = Max<'a, DFIter<'a, T>, T>;
 pub type MinIter<'a, T> = Min<'a, DFIter<'a, T>, T>;
 pub type StdevIter<'a, T> = Stdev<'a, DFIter<'a, T>, T>;
 pub type MeanIter<'a, T> = Mean<'a, DFIter<'a, T>, T>;
+pub type NUniqueIter<'a, T> = NUnique<'a, DFIter<'a, T>, T>;
 pub type MapDFIter<'a, T, F> = MapDF<'a, T, DataFrameMutIterator<'a, T>, F>;
 pub type ImputeIter<'a, T> = Impute<'a, DataFrameMutIterator<'a, T>, T>;
 